@@ -1,175 +1,785 @@
-// Auto-generated with deno_bindgen
-import { CachePolicy, prepare } from "../plug/plug.ts"
+// Bindings for the native library built with `deno task build:native`
+import { CachePolicy, prepare } from "../plug/plug.ts";
 function encode(v: string | Uint8Array): Uint8Array {
-  if (typeof v !== "string") return v
-  return new TextEncoder().encode(v)
+  if (typeof v !== "string") return v;
+  return new TextEncoder().encode(v);
 }
 function decode(v: Uint8Array): string {
-  return new TextDecoder().decode(v)
+  return new TextDecoder().decode(v);
 }
 function readPointer(v: any): Uint8Array {
-  const ptr = new Deno.UnsafePointerView(v as Deno.UnsafePointer)
-  const lengthBe = new Uint8Array(4)
-  const view = new DataView(lengthBe.buffer)
-  ptr.copyInto(lengthBe, 0)
-  const buf = new Uint8Array(view.getUint32(0))
-  ptr.copyInto(buf, 4)
-  return buf
+  const ptr = new Deno.UnsafePointerView(v as Deno.UnsafePointer);
+  const lengthBe = new Uint8Array(4);
+  const view = new DataView(lengthBe.buffer);
+  ptr.copyInto(lengthBe, 0);
+  const buf = new Uint8Array(view.getUint32(0));
+  ptr.copyInto(buf, 4);
+  _lib.symbols.free_buffer(v);
+  return buf;
+}
+function readJson(v: any): any {
+  return JSON.parse(decode(readPointer(v)));
+}
+function readResult(v: any): any {
+  const result = readJson(v);
+  if ("Err" in result) throw new Error(result.Err);
+  return result.Ok;
 }
 const opts = {
   name: "deno_jieba",
   url: (new URL("../target/release", import.meta.url)).toString(),
   policy: undefined,
-}
+};
 const _lib = await prepare(opts, {
+  free_buffer: { parameters: ["pointer"], result: "void", nonblocking: false },
+  segmenter_new: { parameters: [], result: "pointer", nonblocking: false },
+  segmenter_empty: { parameters: [], result: "pointer", nonblocking: false },
+  segmenter_with_dict: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  segmenter_from_snapshot: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  segmenter_free: {
+    parameters: ["pointer"],
+    result: "void",
+    nonblocking: false,
+  },
+  add_stop_word: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "u8",
+    nonblocking: false,
+  },
   add_word: {
-    parameters: ["pointer", "usize", "i32", "pointer", "usize"],
+    parameters: ["pointer", "pointer", "usize", "i32", "pointer", "usize"],
     result: "usize",
     nonblocking: false,
   },
+  begin: { parameters: ["pointer"], result: "usize", nonblocking: false },
+  commit: { parameters: ["pointer"], result: "pointer", nonblocking: false },
   cut: {
-    parameters: ["pointer", "usize", "u8"],
+    parameters: ["pointer", "pointer", "usize", "u8"],
     result: "pointer",
     nonblocking: true,
   },
   cut_all: {
-    parameters: ["pointer", "usize"],
+    parameters: ["pointer", "pointer", "usize"],
     result: "pointer",
     nonblocking: true,
   },
   cut_for_search: {
-    parameters: ["pointer", "usize", "u8"],
+    parameters: ["pointer", "pointer", "usize", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  export_dict: {
+    parameters: ["pointer", "u8"],
     result: "pointer",
     nonblocking: true,
   },
   extract_tags_by_textrank: {
-    parameters: ["pointer", "usize", "usize", "pointer", "usize"],
+    parameters: ["pointer", "pointer", "usize", "usize", "pointer", "usize"],
     result: "pointer",
     nonblocking: true,
   },
   extract_tags_by_tfidf: {
-    parameters: ["pointer", "usize", "usize", "pointer", "usize"],
+    parameters: ["pointer", "pointer", "usize", "usize", "pointer", "usize"],
     result: "pointer",
     nonblocking: true,
   },
-  get_col_separator: { parameters: [], result: "pointer", nonblocking: false },
-  get_row_separator: { parameters: [], result: "pointer", nonblocking: false },
+  get_stop_words: {
+    parameters: ["pointer"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  get_normalize_options: {
+    parameters: ["pointer"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  get_textrank_options: {
+    parameters: ["pointer"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  is_traditional: {
+    parameters: ["pointer"],
+    result: "u8",
+    nonblocking: false,
+  },
   load_dict: {
-    parameters: ["pointer", "usize"],
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  load_idf: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  load_stop_words: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  load_snapshot: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  lookup: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  lookup_batch: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  normalize: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  pinyin: {
+    parameters: ["pointer", "pointer", "usize", "u8", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  pinyin_initials: {
+    parameters: ["pointer", "pointer", "usize", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  prefix_search: {
+    parameters: ["pointer", "pointer", "usize", "usize", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  remove_stop_word: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "u8",
+    nonblocking: false,
+  },
+  remove_word: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "u8",
+    nonblocking: true,
+  },
+  remove_words: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  reset: { parameters: ["pointer"], result: "void", nonblocking: false },
+  rollback: { parameters: ["pointer"], result: "pointer", nonblocking: false },
+  set_normalize_options: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  set_traditional: {
+    parameters: ["pointer", "u8"],
+    result: "void",
+    nonblocking: false,
+  },
+  set_stop_words: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  set_textrank_options: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  snapshot: { parameters: ["pointer"], result: "pointer", nonblocking: true },
+  split_sentences: {
+    parameters: ["pointer", "pointer", "usize", "u8"],
     result: "pointer",
     nonblocking: true,
   },
-  reset: { parameters: [], result: "void", nonblocking: false },
   suggest_freq: {
-    parameters: ["pointer", "usize"],
+    parameters: ["pointer", "pointer", "usize", "u8"],
     result: "usize",
     nonblocking: false,
   },
+  suggest_split_freq: {
+    parameters: ["pointer", "pointer", "usize", "u8"],
+    result: "pointer",
+    nonblocking: false,
+  },
   tag: {
-    parameters: ["pointer", "usize", "u8"],
+    parameters: ["pointer", "pointer", "usize", "u8"],
     result: "pointer",
     nonblocking: true,
   },
   tokenize: {
-    parameters: ["pointer", "usize", "u8", "u8"],
+    parameters: ["pointer", "pointer", "usize", "u8", "u8", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  tag_names: { parameters: ["pointer"], result: "pointer", nonblocking: false },
+  tokenize_binary: {
+    parameters: ["pointer", "pointer", "usize", "u8", "u8", "u8", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  tokenize_batch: {
+    parameters: ["pointer", "pointer", "usize", "pointer", "usize", "u8", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  tokenize_sentences: {
+    parameters: ["pointer", "pointer", "usize", "u8", "u8", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  to_simplified: {
+    parameters: ["pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  to_traditional: {
+    parameters: ["pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  token_stream_new: {
+    parameters: ["pointer", "u8", "u8", "u8"],
+    result: "pointer",
+    nonblocking: false,
+  },
+  token_stream_push: {
+    parameters: ["pointer", "pointer", "usize"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  token_stream_finish: {
+    parameters: ["pointer"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  token_stream_free: {
+    parameters: ["pointer"],
+    result: "void",
+    nonblocking: false,
+  },
+  tokenize_with_tags: {
+    parameters: ["pointer", "pointer", "usize", "u8", "u8", "u8"],
+    result: "pointer",
+    nonblocking: true,
+  },
+  tokenize_with_pinyin: {
+    parameters: ["pointer", "pointer", "usize", "u8", "u8", "u8", "u8"],
     result: "pointer",
     nonblocking: true,
   },
-})
+});
+
+/** Handle of a native segmenter, `null` for the default instance */
+export type Handle = Deno.UnsafePointer | null;
+
+/** Number of nonblocking calls running on each segmenter */
+const pending = new Map<Handle, number>();
+/** Segmenters to free once their last nonblocking call finishes */
+const freeing = new Set<Handle>();
+function track<T>(h: Handle, call: () => Promise<T>): Promise<T> {
+  if (h === null) return call();
+  if (freeing.has(h)) return Promise.reject(new Error("segmenter was freed"));
+  pending.set(h, (pending.get(h) ?? 0) + 1);
+  return call().finally(() => {
+    const count = pending.get(h)! - 1;
+    if (count > 0) {
+      pending.set(h, count);
+      return;
+    }
+    pending.delete(h);
+    if (freeing.delete(h)) _lib.symbols.segmenter_free(h);
+  });
+}
 
-export function add_word(a0: string, a1: number, a2: string) {
-  const a0_buf = encode(a0)
-  const a2_buf = encode(a2)
+export function segmenter_new(): Handle {
+  return _lib.symbols.segmenter_new() as Deno.UnsafePointer;
+}
+export function segmenter_empty(): Handle {
+  return _lib.symbols.segmenter_empty() as Deno.UnsafePointer;
+}
+async function segmenter_load(
+  load: (h: Handle) => Promise<Deno.UnsafePointer>,
+): Promise<Handle> {
+  const h = segmenter_empty();
+  try {
+    readResult(await load(h));
+  } catch (err) {
+    segmenter_free(h);
+    throw err;
+  }
+  return h;
+}
+export function segmenter_with_dict(a0: Uint8Array): Promise<Handle> {
+  return segmenter_load((h) =>
+    _lib.symbols.segmenter_with_dict(h, a0, a0.byteLength)
+  );
+}
+export function segmenter_from_snapshot(a0: Uint8Array): Promise<Handle> {
+  return segmenter_load((h) =>
+    _lib.symbols.segmenter_from_snapshot(h, a0, a0.byteLength)
+  );
+}
+/** Free a segmenter, once the nonblocking calls on it have finished */
+export function segmenter_free(h: Handle) {
+  if (h === null) return;
+  if (pending.has(h)) freeing.add(h);
+  else _lib.symbols.segmenter_free(h);
+}
+export function add_stop_word(h: Handle, a0: string): boolean {
+  const a0_buf = encode(a0);
+  return _lib.symbols.add_stop_word(h, a0_buf, a0_buf.byteLength) === 1;
+}
+export function add_word(h: Handle, a0: string, a1: number, a2: string) {
+  const a0_buf = encode(a0);
+  const a2_buf = encode(a2);
   let rawResult = _lib.symbols.add_word(
+    h,
     a0_buf,
     a0_buf.byteLength,
     a1,
     a2_buf,
     a2_buf.byteLength,
-  )
-  const result = rawResult
-  return result
-}
-export function cut(a0: string, a1: number) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.cut(a0_buf, a0_buf.byteLength, a1)
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function cut_all(a0: string) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.cut_all(a0_buf, a0_buf.byteLength)
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function cut_for_search(a0: string, a1: number) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.cut_for_search(a0_buf, a0_buf.byteLength, a1)
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function extract_tags_by_textrank(a0: string, a1: number, a2: string) {
-  const a0_buf = encode(a0)
-  const a2_buf = encode(a2)
-  let rawResult = _lib.symbols.extract_tags_by_textrank(
-    a0_buf,
-    a0_buf.byteLength,
-    a1,
-    a2_buf,
-    a2_buf.byteLength,
-  )
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function extract_tags_by_tfidf(a0: string, a1: number, a2: string) {
-  const a0_buf = encode(a0)
-  const a2_buf = encode(a2)
-  let rawResult = _lib.symbols.extract_tags_by_tfidf(
+  );
+  const result = rawResult;
+  return result;
+}
+export function begin(h: Handle) {
+  return _lib.symbols.begin(h);
+}
+export function commit(h: Handle) {
+  readResult(_lib.symbols.commit(h));
+}
+export function cut(h: Handle, a0: string, a1: number): Promise<string[]> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.cut(h, a0_buf, a0_buf.byteLength, a1),
+  );
+  return rawResult.then(readJson);
+}
+export function cut_all(h: Handle, a0: string): Promise<string[]> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.cut_all(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readJson);
+}
+export function cut_for_search(
+  h: Handle,
+  a0: string,
+  a1: number,
+): Promise<string[]> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.cut_for_search(h, a0_buf, a0_buf.byteLength, a1),
+  );
+  return rawResult.then(readJson);
+}
+export function export_dict(h: Handle, a0: boolean): Promise<string> {
+  let rawResult = track(h, () => _lib.symbols.export_dict(h, a0 ? 1 : 0));
+  return rawResult.then(readPointer).then(decode);
+}
+export function extract_tags_by_textrank(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: string[],
+) {
+  const a0_buf = encode(a0);
+  const a2_buf = encode(JSON.stringify(a2));
+  let rawResult = track(h, () =>
+    _lib.symbols.extract_tags_by_textrank(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2_buf,
+      a2_buf.byteLength,
+    ));
+  return rawResult.then(readResult);
+}
+export function extract_tags_by_tfidf(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: string[],
+) {
+  const a0_buf = encode(a0);
+  const a2_buf = encode(JSON.stringify(a2));
+  let rawResult = track(h, () =>
+    _lib.symbols.extract_tags_by_tfidf(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2_buf,
+      a2_buf.byteLength,
+    ));
+  return rawResult.then(readResult);
+}
+export function get_stop_words(h: Handle): string[] {
+  return readJson(_lib.symbols.get_stop_words(h));
+}
+export function get_normalize_options(h: Handle) {
+  return readJson(_lib.symbols.get_normalize_options(h));
+}
+export function get_textrank_options(h: Handle) {
+  return readJson(_lib.symbols.get_textrank_options(h));
+}
+export function is_traditional(h: Handle): boolean {
+  return _lib.symbols.is_traditional(h) === 1;
+}
+export function load_dict(h: Handle, a0: Uint8Array): Promise<void> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.load_dict(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readResult);
+}
+export function load_idf(h: Handle, a0: Uint8Array): Promise<void> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.load_idf(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readResult);
+}
+export function load_stop_words(h: Handle, a0: Uint8Array): Promise<void> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.load_stop_words(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readResult);
+}
+export function load_snapshot(h: Handle, a0: Uint8Array): Promise<void> {
+  let rawResult = track(
+    h,
+    () => _lib.symbols.load_snapshot(h, a0, a0.byteLength),
+  );
+  return rawResult.then(readResult);
+}
+export function lookup(h: Handle, a0: string) {
+  const a0_buf = encode(a0);
+  return readJson(_lib.symbols.lookup(h, a0_buf, a0_buf.byteLength));
+}
+export function lookup_batch(h: Handle, a0: string[]) {
+  const a0_buf = encode(JSON.stringify(a0));
+  let rawResult = track(
+    h,
+    () => _lib.symbols.lookup_batch(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readResult);
+}
+export function normalize(h: Handle, a0: string): Promise<string> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.normalize(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readJson);
+}
+export function pinyin(h: Handle, a0: string, a1: number, a2: number) {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.pinyin(h, a0_buf, a0_buf.byteLength, a1, a2),
+  );
+  return rawResult.then(readJson);
+}
+export function pinyin_initials(
+  h: Handle,
+  a0: string,
+  a1: number,
+): Promise<string> {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.pinyin_initials(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+    ));
+  return rawResult.then(readJson);
+}
+export function prefix_search(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: boolean,
+) {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.prefix_search(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2 ? 1 : 0,
+    ));
+  return rawResult.then(readResult);
+}
+export function remove_stop_word(h: Handle, a0: string): boolean {
+  const a0_buf = encode(a0);
+  return _lib.symbols.remove_stop_word(h, a0_buf, a0_buf.byteLength) === 1;
+}
+export function remove_word(h: Handle, a0: string): Promise<boolean> {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.remove_word(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then((v: number) => v === 1);
+}
+export function remove_words(h: Handle, a0: string[]): Promise<number> {
+  const a0_buf = encode(JSON.stringify(a0));
+  let rawResult = track(
+    h,
+    () => _lib.symbols.remove_words(h, a0_buf, a0_buf.byteLength),
+  );
+  return rawResult.then(readResult);
+}
+export function reset(h: Handle) {
+  let rawResult = _lib.symbols.reset(h);
+  const result = rawResult;
+  return result;
+}
+export function rollback(h: Handle) {
+  readResult(_lib.symbols.rollback(h));
+}
+export function set_normalize_options(h: Handle, a0: object) {
+  const a0_buf = encode(JSON.stringify(a0));
+  readResult(_lib.symbols.set_normalize_options(h, a0_buf, a0_buf.byteLength));
+}
+export function set_traditional(h: Handle, a0: boolean) {
+  _lib.symbols.set_traditional(h, a0 ? 1 : 0);
+}
+export function set_stop_words(h: Handle, a0: string[]) {
+  const a0_buf = encode(JSON.stringify(a0));
+  readResult(_lib.symbols.set_stop_words(h, a0_buf, a0_buf.byteLength));
+}
+export function set_textrank_options(h: Handle, a0: object) {
+  const a0_buf = encode(JSON.stringify(a0));
+  readResult(_lib.symbols.set_textrank_options(h, a0_buf, a0_buf.byteLength));
+}
+export function snapshot(h: Handle): Promise<Uint8Array> {
+  let rawResult = track(h, () => _lib.symbols.snapshot(h));
+  return rawResult.then(readPointer);
+}
+export function split_sentences(h: Handle, a0: string, a1: number) {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.split_sentences(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+    ));
+  return rawResult.then(readJson);
+}
+export function suggest_freq(h: Handle, a0: string, a1: boolean) {
+  const a0_buf = encode(a0);
+  let rawResult = _lib.symbols.suggest_freq(
+    h,
     a0_buf,
     a0_buf.byteLength,
-    a1,
-    a2_buf,
-    a2_buf.byteLength,
-  )
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function get_col_separator() {
-  let rawResult = _lib.symbols.get_col_separator()
-  const result = readPointer(rawResult)
-  return decode(result)
-}
-export function get_row_separator() {
-  let rawResult = _lib.symbols.get_row_separator()
-  const result = readPointer(rawResult)
-  return decode(result)
-}
-export function load_dict(a0: Uint8Array) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.load_dict(a0_buf, a0_buf.byteLength)
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function reset() {
-  let rawResult = _lib.symbols.reset()
-  const result = rawResult
-  return result
-}
-export function suggest_freq(a0: string) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.suggest_freq(a0_buf, a0_buf.byteLength)
-  const result = rawResult
-  return result
-}
-export function tag(a0: string, a1: number) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.tag(a0_buf, a0_buf.byteLength, a1)
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
-}
-export function tokenize(a0: string, a1: number, a2: number) {
-  const a0_buf = encode(a0)
-  let rawResult = _lib.symbols.tokenize(a0_buf, a0_buf.byteLength, a1, a2)
-  const result = rawResult.then(readPointer)
-  return result.then(decode)
+    a1 ? 1 : 0,
+  );
+  const result = rawResult;
+  return result;
+}
+export function suggest_split_freq(
+  h: Handle,
+  a0: string[],
+  a1: boolean,
+): number {
+  const a0_buf = encode(JSON.stringify(a0));
+  return readResult(
+    _lib.symbols.suggest_split_freq(h, a0_buf, a0_buf.byteLength, a1 ? 1 : 0),
+  );
+}
+export function tag(h: Handle, a0: string, a1: number) {
+  const a0_buf = encode(a0);
+  let rawResult = track(
+    h,
+    () => _lib.symbols.tag(h, a0_buf, a0_buf.byteLength, a1),
+  );
+  return rawResult.then(readJson);
+}
+export function tokenize(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: number,
+  a3: number,
+) {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.tokenize(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2,
+      a3,
+    ));
+  return rawResult.then(readJson);
+}
+export function tag_names(h: Handle): string[] {
+  return readJson(_lib.symbols.tag_names(h));
+}
+export function tokenize_binary(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: number,
+  a3: number,
+  a4: boolean,
+): Promise<{ offsets: Uint32Array; tags: Uint32Array }> {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.tokenize_binary(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2,
+      a3,
+      a4 ? 1 : 0,
+    ));
+  return rawResult.then(readPointer).then((buf: Uint8Array) => {
+    const values = new Uint32Array(buf.buffer);
+    const length = values[0];
+    return {
+      offsets: values.subarray(1, 1 + length * 2),
+      tags: values.subarray(1 + length * 2),
+    };
+  });
+}
+export function tokenize_batch(
+  h: Handle,
+  a0: string,
+  a1: Uint32Array,
+  a2: number,
+  a3: number,
+): Promise<Uint32Array> {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.tokenize_batch(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a1.length,
+      a2,
+      a3,
+    ));
+  return rawResult.then(readResult).then((v: number[]) => new Uint32Array(v));
+}
+export function tokenize_sentences(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: number,
+  a3: number,
+) {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.tokenize_sentences(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2,
+      a3,
+    ));
+  return rawResult.then(readJson);
+}
+/** Handle of a native token stream */
+export type StreamHandle = Deno.UnsafePointer;
+export function to_simplified(a0: string): Promise<string> {
+  const a0_buf = encode(a0);
+  let rawResult = _lib.symbols.to_simplified(a0_buf, a0_buf.byteLength);
+  return rawResult.then(readJson);
+}
+export function to_traditional(a0: string): Promise<string> {
+  const a0_buf = encode(a0);
+  let rawResult = _lib.symbols.to_traditional(a0_buf, a0_buf.byteLength);
+  return rawResult.then(readJson);
+}
+export function token_stream_new(
+  h: Handle,
+  a0: number,
+  a1: number,
+  a2: number,
+): StreamHandle {
+  return _lib.symbols.token_stream_new(h, a0, a1, a2) as Deno.UnsafePointer;
+}
+export function token_stream_push(s: StreamHandle, a0: string) {
+  const a0_buf = encode(a0);
+  let rawResult = _lib.symbols.token_stream_push(s, a0_buf, a0_buf.byteLength);
+  return rawResult.then(readJson);
+}
+export function token_stream_finish(s: StreamHandle) {
+  let rawResult = _lib.symbols.token_stream_finish(s);
+  return rawResult.then(readJson);
+}
+export function token_stream_free(s: StreamHandle) {
+  _lib.symbols.token_stream_free(s);
+}
+export function tokenize_with_tags(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: number,
+  a3: number,
+) {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.tokenize_with_tags(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2,
+      a3,
+    ));
+  return rawResult.then(readJson);
+}
+export function tokenize_with_pinyin(
+  h: Handle,
+  a0: string,
+  a1: number,
+  a2: number,
+  a3: number,
+  a4: number,
+) {
+  const a0_buf = encode(a0);
+  let rawResult = track(h, () =>
+    _lib.symbols.tokenize_with_pinyin(
+      h,
+      a0_buf,
+      a0_buf.byteLength,
+      a1,
+      a2,
+      a3,
+      a4,
+    ));
+  return rawResult.then(readJson);
 }
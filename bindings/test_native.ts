@@ -0,0 +1,83 @@
+// Smoke test of the native library, run with `deno task test:native`
+import {
+  assertEquals,
+  assertRejects,
+} from "https://deno.land/std@0.148.0/testing/asserts.ts";
+import * as native from "./bindings.ts";
+
+const encoder = new TextEncoder();
+
+Deno.test("Test native cut", async () => {
+  assertEquals(await native.cut(null, "我来到北京清华大学", 0), [
+    "我",
+    "来到",
+    "北京",
+    "清华大学",
+  ]);
+
+  const jieba = native.segmenter_new();
+  native.add_word(jieba, "中出", 10000, "v");
+  assertEquals(await native.cut(jieba, "我们中出了一个叛徒", 0), [
+    "我们",
+    "中出",
+    "了",
+    "一个",
+    "叛徒",
+  ]);
+  native.segmenter_free(jieba);
+});
+
+Deno.test("Test native segmenter from a dictionary", async () => {
+  const jieba = await native.segmenter_with_dict(
+    encoder.encode("中出 100 v\n叛徒 50 n"),
+  );
+  assertEquals(await native.cut(jieba, "中出叛徒", 0), ["中出", "叛徒"]);
+
+  const restored = await native.segmenter_from_snapshot(
+    await native.snapshot(jieba),
+  );
+  assertEquals(await native.cut(restored, "中出叛徒", 0), ["中出", "叛徒"]);
+  native.segmenter_free(restored);
+  native.segmenter_free(jieba);
+
+  await assertRejects(
+    () => native.segmenter_with_dict(encoder.encode("中出 abc")),
+    Error,
+    "line 1",
+  );
+  await assertRejects(() =>
+    native.segmenter_from_snapshot(encoder.encode("not a snapshot"))
+  );
+});
+
+Deno.test("Test native errors", async () => {
+  await assertRejects(() => native.prefix_search(null, "长江", 0, false));
+  await assertRejects(() =>
+    native.extract_tags_by_tfidf(
+      null,
+      "北京烤鸭",
+      3,
+      [1] as unknown as string[],
+    )
+  );
+  await assertRejects(() =>
+    native.extract_tags_by_textrank(
+      null,
+      "北京烤鸭",
+      3,
+      [1] as unknown as string[],
+    )
+  );
+});
+
+Deno.test("Test native free during a call", async () => {
+  const jieba = native.segmenter_new();
+  const pending = native.cut(jieba, "我来到北京清华大学".repeat(1000), 0);
+  native.segmenter_free(jieba);
+  await assertRejects(
+    () => native.cut(jieba, "北京", 0),
+    Error,
+    "segmenter was freed",
+  );
+  assertEquals((await pending).length, 4000);
+});
@@ -1,364 +1,2060 @@
 // @generated file from wasmbuild -- do not edit
 // deno-lint-ignore-file
 // deno-fmt-ignore-file
-// source-hash: 0f770111bf2c16b795a33978c0a155851f4a1a4d
+// source-hash: 07445ed5926d81c3a08b29f7cb15d2f95985b029
 let wasm;
 
-let cachedInt32Memory0;
-function getInt32Memory0() {
-  if (cachedInt32Memory0.byteLength === 0) {
-    cachedInt32Memory0 = new Int32Array(wasm.memory.buffer);
+/**
+ * An independent Jieba instance with its own dictionary
+ */
+export class Segmenter {
+  static __wrap(ptr) {
+    const obj = Object.create(Segmenter.prototype);
+    obj.__wbg_ptr = ptr;
+    SegmenterFinalization.register(obj, obj.__wbg_ptr, obj);
+    return obj;
+  }
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    SegmenterFinalization.unregister(this);
+    return ptr;
+  }
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_segmenter_free(ptr, 0);
+  }
+  /**
+   * Add a stop word ignored by keyword extraction, return `false` if it was already present
+   * @param {string} word
+   * @returns {boolean}
+   */
+  add_stop_word(word) {
+    const ptr0 = passStringToWasm0(
+      word,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_add_stop_word(this.__wbg_ptr, ptr0, len0);
+    return ret !== 0;
+  }
+  /**
+   * @param {string} word
+   * @param {number} freq
+   * @param {string} tag
+   * @returns {number}
+   */
+  add_word(word, freq, tag) {
+    const ptr0 = passStringToWasm0(
+      word,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(
+      tag,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len1 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_add_word(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      freq,
+      ptr1,
+      len1,
+    );
+    return ret >>> 0;
+  }
+  /**
+   * Start a transaction around dictionary changes, return how many are open
+   * @returns {number}
+   */
+  begin() {
+    const ret = wasm.segmenter_begin(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+   * Keep the dictionary changes of the innermost transaction
+   */
+  commit() {
+    const ret = wasm.segmenter_commit(this.__wbg_ptr);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * @param {string} sentence
+   * @param {number} hmm
+   * @returns {any}
+   */
+  cut(sentence, hmm) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_cut(this.__wbg_ptr, ptr0, len0, hmm);
+    return ret;
+  }
+  /**
+   * @param {string} sentence
+   * @returns {any}
+   */
+  cut_all(sentence) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_cut_all(this.__wbg_ptr, ptr0, len0);
+    return ret;
+  }
+  /**
+   * @param {string} sentence
+   * @param {number} hmm
+   * @returns {any}
+   */
+  cut_for_search(sentence, hmm) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_cut_for_search(this.__wbg_ptr, ptr0, len0, hmm);
+    return ret;
+  }
+  /**
+   * Create an instance with an empty dictionary
+   * @returns {Segmenter}
+   */
+  static empty() {
+    const ret = wasm.segmenter_empty();
+    return Segmenter.__wrap(ret);
+  }
+  /**
+   * Write the dictionary in `word freq tag` format, or with `delta` only
+   * the words removed, added or changed since the dictionary it started
+   * with
+   * @param {boolean} delta
+   * @returns {string}
+   */
+  export_dict(delta) {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.segmenter_export_dict(this.__wbg_ptr, delta);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+   * @param {string} sentence
+   * @param {number} top_k
+   * @param {any} allowed_pos
+   * @returns {any}
+   */
+  extract_tags_by_textrank(sentence, top_k, allowed_pos) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_extract_tags_by_textrank(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      top_k,
+      allowed_pos,
+    );
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+  /**
+   * @param {string} sentence
+   * @param {number} top_k
+   * @param {any} allowed_pos
+   * @returns {any}
+   */
+  extract_tags_by_tfidf(sentence, top_k, allowed_pos) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_extract_tags_by_tfidf(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      top_k,
+      allowed_pos,
+    );
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+  /**
+   * Create an instance from a dictionary snapshot, without building the
+   * default dictionary
+   * @param {Uint8Array} buf
+   * @returns {Segmenter}
+   */
+  static from_snapshot(buf) {
+    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_from_snapshot(ptr0, len0);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return Segmenter.__wrap(ret[0]);
+  }
+  /**
+   * Get the current normalization steps
+   * @returns {any}
+   */
+  get_normalize_options() {
+    const ret = wasm.segmenter_get_normalize_options(this.__wbg_ptr);
+    return ret;
+  }
+  /**
+   * List the current stop words in sorted order
+   * @returns {any}
+   */
+  get_stop_words() {
+    const ret = wasm.segmenter_get_stop_words(this.__wbg_ptr);
+    return ret;
+  }
+  /**
+   * Get the current TextRank parameters
+   * @returns {any}
+   */
+  get_textrank_options() {
+    const ret = wasm.segmenter_get_textrank_options(this.__wbg_ptr);
+    return ret;
+  }
+  /**
+   * @returns {boolean}
+   */
+  is_traditional() {
+    const ret = wasm.segmenter_is_traditional(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+   * Load a dictionary in `word freq tag` format.
+   *
+   * The whole buffer is validated first, so the dictionary is left
+   * unchanged when any line is invalid.
+   * @param {Uint8Array} buf
+   */
+  load_dict(buf) {
+    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_load_dict(this.__wbg_ptr, ptr0, len0);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Merge an IDF table in `word idf` format into the one used by TF-IDF.
+   *
+   * The whole buffer is validated first, so the table is left unchanged
+   * when any line is invalid.
+   * @param {Uint8Array} buf
+   */
+  load_idf(buf) {
+    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_load_idf(this.__wbg_ptr, ptr0, len0);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Replace the dictionary with the one of a snapshot, left unchanged
+   * when the snapshot is invalid
+   * @param {Uint8Array} buf
+   */
+  load_snapshot(buf) {
+    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_load_snapshot(this.__wbg_ptr, ptr0, len0);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Add the stop words of a file with one word per line
+   * @param {Uint8Array} buf
+   */
+  load_stop_words(buf) {
+    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_load_stop_words(this.__wbg_ptr, ptr0, len0);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Whether a word is in the dictionary, with its frequency and tag
+   * @param {string} word
+   * @returns {any}
+   */
+  lookup(word) {
+    const ptr0 = passStringToWasm0(
+      word,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_lookup(this.__wbg_ptr, ptr0, len0);
+    return ret;
+  }
+  /**
+   * Look up the given array of words at once
+   * @param {any} words
+   * @returns {any}
+   */
+  lookup_batch(words) {
+    const ret = wasm.segmenter_lookup_batch(this.__wbg_ptr, words);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+  constructor() {
+    const ret = wasm.segmenter_new();
+    this.__wbg_ptr = ret;
+    SegmenterFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+   * Normalize a text the way it is before segmentation
+   * @param {string} text
+   * @returns {string}
+   */
+  normalize(text) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+      const ptr0 = passStringToWasm0(
+        text,
+        wasm.__wbindgen_malloc,
+        wasm.__wbindgen_realloc,
+      );
+      const len0 = WASM_VECTOR_LEN;
+      const ret = wasm.segmenter_normalize(this.__wbg_ptr, ptr0, len0);
+      deferred2_0 = ret[0];
+      deferred2_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+  }
+  /**
+   * Cut a sentence with the pinyin of every word, in the given style
+   * (0: tone marks, 1: tone numbers, 2: plain, 3: first letters)
+   * @param {string} sentence
+   * @param {number} style
+   * @param {number} hmm
+   * @returns {any}
+   */
+  pinyin(sentence, style, hmm) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_pinyin(this.__wbg_ptr, ptr0, len0, style, hmm);
+    return ret;
+  }
+  /**
+   * Abbreviate a sentence to the first letters of its pinyin
+   * @param {string} sentence
+   * @param {number} hmm
+   * @returns {string}
+   */
+  pinyin_initials(sentence, hmm) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+      const ptr0 = passStringToWasm0(
+        sentence,
+        wasm.__wbindgen_malloc,
+        wasm.__wbindgen_realloc,
+      );
+      const len0 = WASM_VECTOR_LEN;
+      const ret = wasm.segmenter_pinyin_initials(
+        this.__wbg_ptr,
+        ptr0,
+        len0,
+        hmm,
+      );
+      deferred2_0 = ret[0];
+      deferred2_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+  }
+  /**
+   * Words starting with `prefix`, or with `fuzzy` also those starting
+   * within one edit of it, most frequent first and at most `limit`, which
+   * has to be at least 1
+   * @param {string} prefix
+   * @param {number} limit
+   * @param {boolean} fuzzy
+   * @returns {any}
+   */
+  prefix_search(prefix, limit, fuzzy) {
+    const ptr0 = passStringToWasm0(
+      prefix,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_prefix_search(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      limit,
+      fuzzy,
+    );
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+  /**
+   * Remove a stop word, return `false` if it was not present
+   * @param {string} word
+   * @returns {boolean}
+   */
+  remove_stop_word(word) {
+    const ptr0 = passStringToWasm0(
+      word,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_remove_stop_word(this.__wbg_ptr, ptr0, len0);
+    return ret !== 0;
+  }
+  /**
+   * Remove a word and its tag, return `false` if it was not in the dictionary
+   * @param {string} word
+   * @returns {boolean}
+   */
+  remove_word(word) {
+    const ptr0 = passStringToWasm0(
+      word,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_remove_word(this.__wbg_ptr, ptr0, len0);
+    return ret !== 0;
+  }
+  /**
+   * Remove the given array of words at once, which is much faster than
+   * one by one, return how many were in the dictionary
+   * @param {any} words
+   * @returns {number}
+   */
+  remove_words(words) {
+    const ret = wasm.segmenter_remove_words(this.__wbg_ptr, words);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] >>> 0;
   }
-  return cachedInt32Memory0;
+  reset() {
+    wasm.segmenter_reset(this.__wbg_ptr);
+  }
+  /**
+   * Undo the dictionary changes of the innermost transaction
+   */
+  rollback() {
+    const ret = wasm.segmenter_rollback(this.__wbg_ptr);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Set the normalization run before segmentation, missing steps are off
+   * @param {any} options
+   */
+  set_normalize_options(options) {
+    const ret = wasm.segmenter_set_normalize_options(this.__wbg_ptr, options);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Replace all stop words with the given array of words
+   * @param {any} stop_words
+   */
+  set_stop_words(stop_words) {
+    const ret = wasm.segmenter_set_stop_words(this.__wbg_ptr, stop_words);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Set the TextRank parameters, missing fields fall back to their defaults
+   * @param {any} options
+   */
+  set_textrank_options(options) {
+    const ret = wasm.segmenter_set_textrank_options(this.__wbg_ptr, options);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+   * Segment Traditional Chinese by converting it to Simplified Chinese
+   * @param {boolean} traditional
+   */
+  set_traditional(traditional) {
+    wasm.segmenter_set_traditional(this.__wbg_ptr, traditional);
+  }
+  /**
+   * Write the whole dictionary into a binary snapshot
+   * @returns {Uint8Array}
+   */
+  snapshot() {
+    const ret = wasm.segmenter_snapshot(this.__wbg_ptr);
+    var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    return v1;
+  }
+  /**
+   * Split a document into sentences, reporting positions in the given
+   * unit (0: chars, 1: UTF-8 bytes, 2: UTF-16 code units)
+   * @param {string} text
+   * @param {number} unit
+   * @returns {any}
+   */
+  split_sentences(text, unit) {
+    const ptr0 = passStringToWasm0(
+      text,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_split_sentences(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      unit,
+    );
+    return ret;
+  }
+  /**
+   * Frequency that forces a word to be joined, applied with `tune`
+   * @param {string} segment
+   * @param {boolean} tune
+   * @returns {number}
+   */
+  suggest_freq(segment, tune) {
+    const ptr0 = passStringToWasm0(
+      segment,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_suggest_freq(this.__wbg_ptr, ptr0, len0, tune);
+    return ret >>> 0;
+  }
+  /**
+   * Frequency that forces the given array of pieces apart, applied with `tune`
+   * @param {any} pieces
+   * @param {boolean} tune
+   * @returns {number}
+   */
+  suggest_split_freq(pieces, tune) {
+    const ret = wasm.segmenter_suggest_split_freq(this.__wbg_ptr, pieces, tune);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] >>> 0;
+  }
+  /**
+   * @param {string} sentence
+   * @param {number} hmm
+   * @returns {any}
+   */
+  tag(sentence, hmm) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tag(this.__wbg_ptr, ptr0, len0, hmm);
+    return ret;
+  }
+  /**
+   * Names of the tag ids returned by `tokenize_binary`, indexed by id
+   * @returns {any}
+   */
+  tag_names() {
+    const ret = wasm.segmenter_tag_names(this.__wbg_ptr);
+    return ret;
+  }
+  /**
+   * Start tokenizing a text pushed in chunks, reporting positions in the
+   * whole text in the given unit
+   * @param {number} mode
+   * @param {number} hmm
+   * @param {number} unit
+   * @returns {TokenStream}
+   */
+  token_stream(mode, hmm, unit) {
+    const ret = wasm.segmenter_token_stream(this.__wbg_ptr, mode, hmm, unit);
+    return TokenStream.__wrap(ret);
+  }
+  /**
+   * Tokenize a sentence, reporting positions in the given unit
+   * (0: chars, 1: UTF-8 bytes, 2: UTF-16 code units)
+   * @param {string} sentence
+   * @param {number} mode
+   * @param {number} hmm
+   * @param {number} unit
+   * @returns {any}
+   */
+  tokenize(sentence, mode, hmm, unit) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tokenize(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      mode,
+      hmm,
+      unit,
+    );
+    return ret;
+  }
+  /**
+   * Tokenize many documents in one call.
+   *
+   * `text` is the concatenation of all documents and `lengths` their
+   * lengths in UTF-16 code units. The result holds, for each document in
+   * order, its token count followed by the `start, end` pair of every
+   * token in UTF-16 code units relative to the document.
+   * @param {string} text
+   * @param {Uint32Array} lengths
+   * @param {number} mode
+   * @param {number} hmm
+   * @returns {Uint32Array}
+   */
+  tokenize_batch(text, lengths, mode, hmm) {
+    const ptr0 = passStringToWasm0(
+      text,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passArray32ToWasm0(lengths, wasm.__wbindgen_malloc);
+    const len1 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tokenize_batch(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      ptr1,
+      len1,
+      mode,
+      hmm,
+    );
+    if (ret[3]) {
+      throw takeFromExternrefTable0(ret[2]);
+    }
+    var v3 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+    return v3;
+  }
+  /**
+   * Tokenize a sentence into flat arrays of positions in the given unit
+   * and, if `with_tags` is set, of tag ids, see `tag_names`
+   * @param {string} sentence
+   * @param {number} mode
+   * @param {number} hmm
+   * @param {number} unit
+   * @param {boolean} with_tags
+   * @returns {TokenBuffer}
+   */
+  tokenize_binary(sentence, mode, hmm, unit, with_tags) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tokenize_binary(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      mode,
+      hmm,
+      unit,
+      with_tags,
+    );
+    return TokenBuffer.__wrap(ret);
+  }
+  /**
+   * Tokenize a document sentence by sentence, reporting positions in the
+   * document in the given unit
+   * @param {string} text
+   * @param {number} mode
+   * @param {number} hmm
+   * @param {number} unit
+   * @returns {any}
+   */
+  tokenize_sentences(text, mode, hmm, unit) {
+    const ptr0 = passStringToWasm0(
+      text,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tokenize_sentences(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      mode,
+      hmm,
+      unit,
+    );
+    return ret;
+  }
+  /**
+   * Tokenize a sentence with the pinyin of every token, reporting
+   * positions in the given unit
+   * @param {string} sentence
+   * @param {number} mode
+   * @param {number} hmm
+   * @param {number} unit
+   * @param {number} style
+   * @returns {any}
+   */
+  tokenize_with_pinyin(sentence, mode, hmm, unit, style) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tokenize_with_pinyin(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      mode,
+      hmm,
+      unit,
+      style,
+    );
+    return ret;
+  }
+  /**
+   * Tokenize a sentence with the tag of every token, reporting positions
+   * in the given unit (0: chars, 1: UTF-8 bytes, 2: UTF-16 code units)
+   * @param {string} sentence
+   * @param {number} mode
+   * @param {number} hmm
+   * @param {number} unit
+   * @returns {any}
+   */
+  tokenize_with_tags(sentence, mode, hmm, unit) {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_tokenize_with_tags(
+      this.__wbg_ptr,
+      ptr0,
+      len0,
+      mode,
+      hmm,
+      unit,
+    );
+    return ret;
+  }
+  /**
+   * Create an instance from a dictionary in `word freq tag` format
+   * instead of the default one
+   * @param {Uint8Array} buf
+   * @returns {Segmenter}
+   */
+  static with_dict(buf) {
+    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.segmenter_with_dict(ptr0, len0);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return Segmenter.__wrap(ret[0]);
+  }
+}
+if (Symbol.dispose) {
+  Segmenter.prototype[Symbol.dispose] = Segmenter.prototype.free;
+}
+
+/**
+ * Tokens kept in wasm memory as flat `u32` arrays, read by JS through
+ * views at the given addresses without copying
+ */
+export class TokenBuffer {
+  static __wrap(ptr) {
+    const obj = Object.create(TokenBuffer.prototype);
+    obj.__wbg_ptr = ptr;
+    TokenBufferFinalization.register(obj, obj.__wbg_ptr, obj);
+    return obj;
+  }
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    TokenBufferFinalization.unregister(this);
+    return ptr;
+  }
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_tokenbuffer_free(ptr, 0);
+  }
+  /**
+   * Number of tokens
+   * @returns {number}
+   */
+  get length() {
+    const ret = wasm.tokenbuffer_length(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+   * Address of the `start, end` pairs, `2 * length` values
+   * @returns {number}
+   */
+  offsets_ptr() {
+    const ret = wasm.tokenbuffer_offsets_ptr(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+   * Address of the tag ids, `length` values if tags were requested
+   * @returns {number}
+   */
+  tags_ptr() {
+    const ret = wasm.tokenbuffer_tags_ptr(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+}
+if (Symbol.dispose) {
+  TokenBuffer.prototype[Symbol.dispose] = TokenBuffer.prototype.free;
+}
+
+/**
+ * Tokenizes a text pushed in chunks, see `Segmenter::token_stream`
+ */
+export class TokenStream {
+  static __wrap(ptr) {
+    const obj = Object.create(TokenStream.prototype);
+    obj.__wbg_ptr = ptr;
+    TokenStreamFinalization.register(obj, obj.__wbg_ptr, obj);
+    return obj;
+  }
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    TokenStreamFinalization.unregister(this);
+    return ptr;
+  }
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_tokenstream_free(ptr, 0);
+  }
+  /**
+   * Tokenize the rest of the text and start over with a new one
+   * @returns {any}
+   */
+  finish() {
+    const ret = wasm.tokenstream_finish(this.__wbg_ptr);
+    return ret;
+  }
+  /**
+   * Add a chunk of text, return the tokens completed by it
+   * @param {string} chunk
+   * @returns {any}
+   */
+  push(chunk) {
+    const ptr0 = passStringToWasm0(
+      chunk,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.tokenstream_push(this.__wbg_ptr, ptr0, len0);
+    return ret;
+  }
+}
+if (Symbol.dispose) {
+  TokenStream.prototype[Symbol.dispose] = TokenStream.prototype.free;
+}
+
+/**
+ * @param {string} word
+ * @returns {boolean}
+ */
+export function add_stop_word(word) {
+  const ptr0 = passStringToWasm0(
+    word,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.add_stop_word(ptr0, len0);
+  return ret !== 0;
+}
+
+/**
+ * @param {string} word
+ * @param {number} freq
+ * @param {string} tag
+ * @returns {number}
+ */
+export function add_word(word, freq, tag) {
+  const ptr0 = passStringToWasm0(
+    word,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ptr1 = passStringToWasm0(
+    tag,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len1 = WASM_VECTOR_LEN;
+  const ret = wasm.add_word(ptr0, len0, freq, ptr1, len1);
+  return ret >>> 0;
 }
 
-const cachedTextDecoder = new TextDecoder("utf-8", {
-  ignoreBOM: true,
-  fatal: true,
-});
-
-cachedTextDecoder.decode();
+/**
+ * @returns {number}
+ */
+export function begin() {
+  const ret = wasm.begin();
+  return ret >>> 0;
+}
 
-let cachedUint8Memory0;
-function getUint8Memory0() {
-  if (cachedUint8Memory0.byteLength === 0) {
-    cachedUint8Memory0 = new Uint8Array(wasm.memory.buffer);
+export function commit() {
+  const ret = wasm.commit();
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
   }
-  return cachedUint8Memory0;
 }
 
-function getStringFromWasm0(ptr, len) {
-  return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
+/**
+ * @param {string} sentence
+ * @param {number} hmm
+ * @returns {any}
+ */
+export function cut(sentence, hmm) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.cut(ptr0, len0, hmm);
+  return ret;
+}
+
+/**
+ * @param {string} sentence
+ * @returns {any}
+ */
+export function cut_all(sentence) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.cut_all(ptr0, len0);
+  return ret;
+}
+
+/**
+ * @param {string} sentence
+ * @param {number} hmm
+ * @returns {any}
+ */
+export function cut_for_search(sentence, hmm) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.cut_for_search(ptr0, len0, hmm);
+  return ret;
 }
+
 /**
+ * @param {boolean} delta
  * @returns {string}
  */
-export function get_row_separator() {
+export function export_dict(delta) {
+  let deferred1_0;
+  let deferred1_1;
   try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    wasm.get_row_separator(retptr);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
+    const ret = wasm.export_dict(delta);
+    deferred1_0 = ret[0];
+    deferred1_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
   } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+    wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
   }
 }
 
 /**
- * @returns {string}
+ * @param {string} sentence
+ * @param {number} top_k
+ * @param {any} allowed_pos
+ * @returns {any}
  */
-export function get_col_separator() {
-  try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    wasm.get_col_separator(retptr);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+export function extract_tags_by_textrank(sentence, top_k, allowed_pos) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.extract_tags_by_textrank(ptr0, len0, top_k, allowed_pos);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
   }
+  return takeFromExternrefTable0(ret[0]);
 }
 
-let WASM_VECTOR_LEN = 0;
+/**
+ * @param {string} sentence
+ * @param {number} top_k
+ * @param {any} allowed_pos
+ * @returns {any}
+ */
+export function extract_tags_by_tfidf(sentence, top_k, allowed_pos) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.extract_tags_by_tfidf(ptr0, len0, top_k, allowed_pos);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
+  }
+  return takeFromExternrefTable0(ret[0]);
+}
 
-function passArray8ToWasm0(arg, malloc) {
-  const ptr = malloc(arg.length * 1);
-  getUint8Memory0().set(arg, ptr / 1);
-  WASM_VECTOR_LEN = arg.length;
-  return ptr;
+/**
+ * @returns {any}
+ */
+export function get_normalize_options() {
+  const ret = wasm.get_normalize_options();
+  return ret;
+}
+
+/**
+ * @returns {any}
+ */
+export function get_stop_words() {
+  const ret = wasm.get_stop_words();
+  return ret;
+}
+
+/**
+ * @returns {any}
+ */
+export function get_textrank_options() {
+  const ret = wasm.get_textrank_options();
+  return ret;
+}
+
+/**
+ * @returns {boolean}
+ */
+export function is_traditional() {
+  const ret = wasm.is_traditional();
+  return ret !== 0;
 }
+
 /**
  * @param {Uint8Array} buf
- * @returns {string}
  */
 export function load_dict(buf) {
-  try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.load_dict(retptr, ptr0, len0);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+  const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.load_dict(ptr0, len0);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
   }
 }
 
-const cachedTextEncoder = new TextEncoder("utf-8");
-
-const encodeString = function (arg, view) {
-  return cachedTextEncoder.encodeInto(arg, view);
-};
+/**
+ * @param {Uint8Array} buf
+ */
+export function load_idf(buf) {
+  const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.load_idf(ptr0, len0);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
+  }
+}
 
-function passStringToWasm0(arg, malloc, realloc) {
-  if (realloc === undefined) {
-    const buf = cachedTextEncoder.encode(arg);
-    const ptr = malloc(buf.length);
-    getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-    WASM_VECTOR_LEN = buf.length;
-    return ptr;
+/**
+ * @param {Uint8Array} buf
+ */
+export function load_snapshot(buf) {
+  const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.load_snapshot(ptr0, len0);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
   }
+}
 
-  let len = arg.length;
-  let ptr = malloc(len);
+/**
+ * @param {Uint8Array} buf
+ */
+export function load_stop_words(buf) {
+  const ptr0 = passArray8ToWasm0(buf, wasm.__wbindgen_malloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.load_stop_words(ptr0, len0);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
+  }
+}
 
-  const mem = getUint8Memory0();
+/**
+ * @param {string} word
+ * @returns {any}
+ */
+export function lookup(word) {
+  const ptr0 = passStringToWasm0(
+    word,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.lookup(ptr0, len0);
+  return ret;
+}
 
-  let offset = 0;
+/**
+ * @param {any} words
+ * @returns {any}
+ */
+export function lookup_batch(words) {
+  const ret = wasm.lookup_batch(words);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
+  }
+  return takeFromExternrefTable0(ret[0]);
+}
 
-  for (; offset < len; offset++) {
-    const code = arg.charCodeAt(offset);
-    if (code > 0x7F) break;
-    mem[ptr + offset] = code;
+/**
+ * @param {string} text
+ * @returns {string}
+ */
+export function normalize(text) {
+  let deferred2_0;
+  let deferred2_1;
+  try {
+    const ptr0 = passStringToWasm0(
+      text,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.normalize(ptr0, len0);
+    deferred2_0 = ret[0];
+    deferred2_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
+  } finally {
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
   }
+}
 
-  if (offset !== len) {
-    if (offset !== 0) {
-      arg = arg.slice(offset);
-    }
-    ptr = realloc(ptr, len, len = offset + arg.length * 3);
-    const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-    const ret = encodeString(arg, view);
+/**
+ * @param {string} sentence
+ * @param {number} style
+ * @param {number} hmm
+ * @returns {any}
+ */
+export function pinyin(sentence, style, hmm) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.pinyin(ptr0, len0, style, hmm);
+  return ret;
+}
 
-    offset += ret.written;
+/**
+ * @param {string} sentence
+ * @param {number} hmm
+ * @returns {string}
+ */
+export function pinyin_initials(sentence, hmm) {
+  let deferred2_0;
+  let deferred2_1;
+  try {
+    const ptr0 = passStringToWasm0(
+      sentence,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    );
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.pinyin_initials(ptr0, len0, hmm);
+    deferred2_0 = ret[0];
+    deferred2_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
+  } finally {
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
   }
+}
 
-  WASM_VECTOR_LEN = offset;
-  return ptr;
+/**
+ * @param {string} prefix
+ * @param {number} limit
+ * @param {boolean} fuzzy
+ * @returns {any}
+ */
+export function prefix_search(prefix, limit, fuzzy) {
+  const ptr0 = passStringToWasm0(
+    prefix,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.prefix_search(ptr0, len0, limit, fuzzy);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
+  }
+  return takeFromExternrefTable0(ret[0]);
 }
+
 /**
  * @param {string} word
- * @param {number} freq
- * @param {string} tag
- * @returns {number}
+ * @returns {boolean}
  */
-export function add_word(word, freq, tag) {
+export function remove_stop_word(word) {
   const ptr0 = passStringToWasm0(
     word,
     wasm.__wbindgen_malloc,
     wasm.__wbindgen_realloc,
   );
   const len0 = WASM_VECTOR_LEN;
-  const ptr1 = passStringToWasm0(
-    tag,
+  const ret = wasm.remove_stop_word(ptr0, len0);
+  return ret !== 0;
+}
+
+/**
+ * @param {string} word
+ * @returns {boolean}
+ */
+export function remove_word(word) {
+  const ptr0 = passStringToWasm0(
+    word,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.remove_word(ptr0, len0);
+  return ret !== 0;
+}
+
+/**
+ * @param {any} words
+ * @returns {number}
+ */
+export function remove_words(words) {
+  const ret = wasm.remove_words(words);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
+  }
+  return ret[0] >>> 0;
+}
+
+export function reset() {
+  wasm.reset();
+}
+
+export function rollback() {
+  const ret = wasm.rollback();
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
+  }
+}
+
+/**
+ * @param {any} options
+ */
+export function set_normalize_options(options) {
+  const ret = wasm.set_normalize_options(options);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
+  }
+}
+
+/**
+ * @param {any} stop_words
+ */
+export function set_stop_words(stop_words) {
+  const ret = wasm.set_stop_words(stop_words);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
+  }
+}
+
+/**
+ * @param {any} options
+ */
+export function set_textrank_options(options) {
+  const ret = wasm.set_textrank_options(options);
+  if (ret[1]) {
+    throw takeFromExternrefTable0(ret[0]);
+  }
+}
+
+/**
+ * @param {boolean} traditional
+ */
+export function set_traditional(traditional) {
+  wasm.set_traditional(traditional);
+}
+
+/**
+ * @returns {Uint8Array}
+ */
+export function snapshot() {
+  const ret = wasm.snapshot();
+  var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+  wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+  return v1;
+}
+
+/**
+ * @param {string} text
+ * @param {number} unit
+ * @returns {any}
+ */
+export function split_sentences(text, unit) {
+  const ptr0 = passStringToWasm0(
+    text,
     wasm.__wbindgen_malloc,
     wasm.__wbindgen_realloc,
   );
-  const len1 = WASM_VECTOR_LEN;
-  const ret = wasm.add_word(ptr0, len0, freq, ptr1, len1);
-  return ret >>> 0;
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.split_sentences(ptr0, len0, unit);
+  return ret;
 }
 
 /**
  * @param {string} segment
+ * @param {boolean} tune
  * @returns {number}
  */
-export function suggest_freq(segment) {
+export function suggest_freq(segment, tune) {
   const ptr0 = passStringToWasm0(
     segment,
     wasm.__wbindgen_malloc,
     wasm.__wbindgen_realloc,
   );
   const len0 = WASM_VECTOR_LEN;
-  const ret = wasm.suggest_freq(ptr0, len0);
+  const ret = wasm.suggest_freq(ptr0, len0, tune);
   return ret >>> 0;
 }
 
-/** */
-export function reset() {
-  wasm.reset();
+/**
+ * @param {any} pieces
+ * @param {boolean} tune
+ * @returns {number}
+ */
+export function suggest_split_freq(pieces, tune) {
+  const ret = wasm.suggest_split_freq(pieces, tune);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
+  }
+  return ret[0] >>> 0;
 }
 
 /**
  * @param {string} sentence
  * @param {number} hmm
+ * @returns {any}
+ */
+export function tag(sentence, hmm) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.tag(ptr0, len0, hmm);
+  return ret;
+}
+
+/**
+ * @returns {any}
+ */
+export function tag_names() {
+  const ret = wasm.tag_names();
+  return ret;
+}
+
+/**
+ * @param {string} text
  * @returns {string}
  */
-export function cut(sentence, hmm) {
+export function to_simplified(text) {
+  let deferred2_0;
+  let deferred2_1;
   try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
     const ptr0 = passStringToWasm0(
-      sentence,
+      text,
       wasm.__wbindgen_malloc,
       wasm.__wbindgen_realloc,
     );
     const len0 = WASM_VECTOR_LEN;
-    wasm.cut(retptr, ptr0, len0, hmm);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
+    const ret = wasm.to_simplified(ptr0, len0);
+    deferred2_0 = ret[0];
+    deferred2_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
   } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
   }
 }
 
 /**
- * @param {string} sentence
+ * @param {string} text
  * @returns {string}
  */
-export function cut_all(sentence) {
+export function to_traditional(text) {
+  let deferred2_0;
+  let deferred2_1;
   try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
     const ptr0 = passStringToWasm0(
-      sentence,
+      text,
       wasm.__wbindgen_malloc,
       wasm.__wbindgen_realloc,
     );
     const len0 = WASM_VECTOR_LEN;
-    wasm.cut_all(retptr, ptr0, len0);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
+    const ret = wasm.to_traditional(ptr0, len0);
+    deferred2_0 = ret[0];
+    deferred2_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
   } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
   }
 }
 
 /**
- * @param {string} sentence
+ * @param {number} mode
  * @param {number} hmm
- * @returns {string}
+ * @param {number} unit
+ * @returns {TokenStream}
  */
-export function cut_for_search(sentence, hmm) {
-  try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    const ptr0 = passStringToWasm0(
-      sentence,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len0 = WASM_VECTOR_LEN;
-    wasm.cut_for_search(retptr, ptr0, len0, hmm);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
-  }
+export function token_stream(mode, hmm, unit) {
+  const ret = wasm.token_stream(mode, hmm, unit);
+  return TokenStream.__wrap(ret);
 }
 
 /**
  * @param {string} sentence
+ * @param {number} mode
  * @param {number} hmm
- * @returns {string}
+ * @param {number} unit
+ * @returns {any}
  */
-export function tag(sentence, hmm) {
-  try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    const ptr0 = passStringToWasm0(
-      sentence,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len0 = WASM_VECTOR_LEN;
-    wasm.tag(retptr, ptr0, len0, hmm);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+export function tokenize(sentence, mode, hmm, unit) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.tokenize(ptr0, len0, mode, hmm, unit);
+  return ret;
+}
+
+/**
+ * @param {string} text
+ * @param {Uint32Array} lengths
+ * @param {number} mode
+ * @param {number} hmm
+ * @returns {Uint32Array}
+ */
+export function tokenize_batch(text, lengths, mode, hmm) {
+  const ptr0 = passStringToWasm0(
+    text,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ptr1 = passArray32ToWasm0(lengths, wasm.__wbindgen_malloc);
+  const len1 = WASM_VECTOR_LEN;
+  const ret = wasm.tokenize_batch(ptr0, len0, ptr1, len1, mode, hmm);
+  if (ret[3]) {
+    throw takeFromExternrefTable0(ret[2]);
   }
+  var v3 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+  wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+  return v3;
 }
 
 /**
  * @param {string} sentence
  * @param {number} mode
  * @param {number} hmm
- * @returns {string}
+ * @param {number} unit
+ * @param {boolean} with_tags
+ * @returns {TokenBuffer}
  */
-export function tokenize(sentence, mode, hmm) {
-  try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    const ptr0 = passStringToWasm0(
-      sentence,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len0 = WASM_VECTOR_LEN;
-    wasm.tokenize(retptr, ptr0, len0, mode, hmm);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
-  }
+export function tokenize_binary(sentence, mode, hmm, unit, with_tags) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.tokenize_binary(ptr0, len0, mode, hmm, unit, with_tags);
+  return TokenBuffer.__wrap(ret);
+}
+
+/**
+ * @param {string} text
+ * @param {number} mode
+ * @param {number} hmm
+ * @param {number} unit
+ * @returns {any}
+ */
+export function tokenize_sentences(text, mode, hmm, unit) {
+  const ptr0 = passStringToWasm0(
+    text,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.tokenize_sentences(ptr0, len0, mode, hmm, unit);
+  return ret;
 }
 
 /**
  * @param {string} sentence
- * @param {number} top_k
- * @param {string} allowed_pos
- * @returns {string}
+ * @param {number} mode
+ * @param {number} hmm
+ * @param {number} unit
+ * @param {number} style
+ * @returns {any}
  */
-export function extract_tags_by_tfidf(sentence, top_k, allowed_pos) {
-  try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    const ptr0 = passStringToWasm0(
-      sentence,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len0 = WASM_VECTOR_LEN;
-    const ptr1 = passStringToWasm0(
-      allowed_pos,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len1 = WASM_VECTOR_LEN;
-    wasm.extract_tags_by_tfidf(retptr, ptr0, len0, top_k, ptr1, len1);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
-  }
+export function tokenize_with_pinyin(sentence, mode, hmm, unit, style) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.tokenize_with_pinyin(ptr0, len0, mode, hmm, unit, style);
+  return ret;
 }
 
 /**
  * @param {string} sentence
- * @param {number} top_k
- * @param {string} allowed_pos
- * @returns {string}
+ * @param {number} mode
+ * @param {number} hmm
+ * @param {number} unit
+ * @returns {any}
  */
-export function extract_tags_by_textrank(sentence, top_k, allowed_pos) {
+export function tokenize_with_tags(sentence, mode, hmm, unit) {
+  const ptr0 = passStringToWasm0(
+    sentence,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  );
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.tokenize_with_tags(ptr0, len0, mode, hmm, unit);
+  return ret;
+}
+function __wbg_get_imports() {
+  const import0 = {
+    __proto__: null,
+    __wbg_Error_30c8987f7c2ed4e2: function (arg0, arg1) {
+      const ret = Error(getStringFromWasm0(arg0, arg1));
+      return ret;
+    },
+    __wbg_Number_14af1003b8dd5ead: function (arg0) {
+      const ret = Number(arg0);
+      return ret;
+    },
+    __wbg_String_8564e559799eccda: function (arg0, arg1) {
+      const ret = String(arg1);
+      const ptr1 = passStringToWasm0(
+        ret,
+        wasm.__wbindgen_malloc,
+        wasm.__wbindgen_realloc,
+      );
+      const len1 = WASM_VECTOR_LEN;
+      getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+      getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+    },
+    __wbg___wbindgen_bigint_get_as_i64_a2383202b9353e4c: function (arg0, arg1) {
+      const v = arg1;
+      const ret = typeof v === "bigint" ? v : undefined;
+      getDataViewMemory0().setBigInt64(
+        arg0 + 8 * 1,
+        isLikeNone(ret) ? BigInt(0) : ret,
+        true,
+      );
+      getDataViewMemory0().setInt32(arg0 + 4 * 0, !isLikeNone(ret), true);
+    },
+    __wbg___wbindgen_boolean_get_5b446f51afd21013: function (arg0) {
+      const v = arg0;
+      const ret = typeof v === "boolean" ? v : undefined;
+      return isLikeNone(ret) ? 0xFFFFFF : ret ? 1 : 0;
+    },
+    __wbg___wbindgen_debug_string_4687d8d8c2017d52: function (arg0, arg1) {
+      const ret = debugString(arg1);
+      const ptr1 = passStringToWasm0(
+        ret,
+        wasm.__wbindgen_malloc,
+        wasm.__wbindgen_realloc,
+      );
+      const len1 = WASM_VECTOR_LEN;
+      getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+      getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+    },
+    __wbg___wbindgen_in_92f62ee1427d9e49: function (arg0, arg1) {
+      const ret = arg0 in arg1;
+      return ret;
+    },
+    __wbg___wbindgen_is_bigint_b123553bed3bb382: function (arg0) {
+      const ret = typeof arg0 === "bigint";
+      return ret;
+    },
+    __wbg___wbindgen_is_function_1f9d30630b8b1d3d: function (arg0) {
+      const ret = typeof arg0 === "function";
+      return ret;
+    },
+    __wbg___wbindgen_is_object_3c45d4f2dde4e749: function (arg0) {
+      const val = arg0;
+      const ret = typeof val === "object" && val !== null;
+      return ret;
+    },
+    __wbg___wbindgen_is_undefined_8865fb403f8fe9d8: function (arg0) {
+      const ret = arg0 === undefined;
+      return ret;
+    },
+    __wbg___wbindgen_jsval_eq_02babf21faa37971: function (arg0, arg1) {
+      const ret = arg0 === arg1;
+      return ret;
+    },
+    __wbg___wbindgen_jsval_loose_eq_677f21e468d6b461: function (arg0, arg1) {
+      const ret = arg0 == arg1;
+      return ret;
+    },
+    __wbg___wbindgen_number_get_2e0e7dee9f701a71: function (arg0, arg1) {
+      const obj = arg1;
+      const ret = typeof obj === "number" ? obj : undefined;
+      getDataViewMemory0().setFloat64(
+        arg0 + 8 * 1,
+        isLikeNone(ret) ? 0 : ret,
+        true,
+      );
+      getDataViewMemory0().setInt32(arg0 + 4 * 0, !isLikeNone(ret), true);
+    },
+    __wbg___wbindgen_string_get_0380ccaa2f57f0d9: function (arg0, arg1) {
+      const obj = arg1;
+      const ret = typeof obj === "string" ? obj : undefined;
+      var ptr1 = isLikeNone(ret)
+        ? 0
+        : passStringToWasm0(
+          ret,
+          wasm.__wbindgen_malloc,
+          wasm.__wbindgen_realloc,
+        );
+      var len1 = WASM_VECTOR_LEN;
+      getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+      getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+    },
+    __wbg___wbindgen_throw_41e9ee4f547fc59a: function (arg0, arg1) {
+      throw new Error(getStringFromWasm0(arg0, arg1));
+    },
+    __wbg_call_6137034ef55c9d0f: function () {
+      return handleError(function (arg0, arg1) {
+        const ret = arg0.call(arg1);
+        return ret;
+      }, arguments);
+    },
+    __wbg_done_b41a1d26cdb37fb6: function (arg0) {
+      const ret = arg0.done;
+      return ret;
+    },
+    __wbg_get_658f6698067d9515: function () {
+      return handleError(function (arg0, arg1) {
+        const ret = Reflect.get(arg0, arg1);
+        return ret;
+      }, arguments);
+    },
+    __wbg_get_unchecked_288889d017702237: function (arg0, arg1) {
+      const ret = arg0[arg1 >>> 0];
+      return ret;
+    },
+    __wbg_get_with_ref_key_6412cf3094599694: function (arg0, arg1) {
+      const ret = arg0[arg1];
+      return ret;
+    },
+    __wbg_instanceof_ArrayBuffer_a99f175873e5d9b8: function (arg0) {
+      let result;
+      try {
+        result = arg0 instanceof ArrayBuffer;
+      } catch (_) {
+        result = false;
+      }
+      const ret = result;
+      return ret;
+    },
+    __wbg_instanceof_Uint8Array_828cef2aaacafc31: function (arg0) {
+      let result;
+      try {
+        result = arg0 instanceof Uint8Array;
+      } catch (_) {
+        result = false;
+      }
+      const ret = result;
+      return ret;
+    },
+    __wbg_isArray_e15a2ff68ffdbef2: function (arg0) {
+      const ret = Array.isArray(arg0);
+      return ret;
+    },
+    __wbg_isSafeInteger_717808ad6a54bd9e: function (arg0) {
+      const ret = Number.isSafeInteger(arg0);
+      return ret;
+    },
+    __wbg_iterator_e3c31c892080e444: function () {
+      const ret = Symbol.iterator;
+      return ret;
+    },
+    __wbg_length_7f3c00c40364105e: function (arg0) {
+      const ret = arg0.length;
+      return ret;
+    },
+    __wbg_length_d4bdea10311bd9cf: function (arg0) {
+      const ret = arg0.length;
+      return ret;
+    },
+    __wbg_new_1dbf7428bba60a42: function (arg0) {
+      const ret = new Uint8Array(arg0);
+      return ret;
+    },
+    __wbg_new_617a8cdb8bb1130e: function () {
+      const ret = new Object();
+      return ret;
+    },
+    __wbg_new_ee2291f50781bf1d: function () {
+      const ret = new Array();
+      return ret;
+    },
+    __wbg_next_33784799010f1bbe: function (arg0) {
+      const ret = arg0.next;
+      return ret;
+    },
+    __wbg_next_f4aac29c42af995c: function () {
+      return handleError(function (arg0) {
+        const ret = arg0.next();
+        return ret;
+      }, arguments);
+    },
+    __wbg_prototypesetcall_bc27214492979395: function (arg0, arg1, arg2) {
+      Uint8Array.prototype.set.call(getArrayU8FromWasm0(arg0, arg1), arg2);
+    },
+    __wbg_set_6be42768c690e380: function (arg0, arg1, arg2) {
+      arg0[arg1] = arg2;
+    },
+    __wbg_set_bea140a88be9b277: function (arg0, arg1, arg2) {
+      arg0[arg1 >>> 0] = arg2;
+    },
+    __wbg_value_f3c585ee8f5ba40c: function (arg0) {
+      const ret = arg0.value;
+      return ret;
+    },
+    __wbindgen_generic_0000000000000001: function (arg0) {
+      // Cast intrinsic for `F64 -> Externref`.
+      const ret = arg0;
+      return ret;
+    },
+    __wbindgen_generic_0000000000000002: function (arg0, arg1) {
+      // Cast intrinsic for `Ref(String) -> Externref`.
+      const ret = getStringFromWasm0(arg0, arg1);
+      return ret;
+    },
+    __wbindgen_generic_0000000000000003: function (arg0) {
+      // Cast intrinsic for `U64 -> Externref`.
+      const ret = BigInt.asUintN(64, arg0);
+      return ret;
+    },
+    __wbindgen_init_externref_table: function () {
+      const table = wasm.__wbindgen_externrefs;
+      const offset = table.grow(4);
+      table.set(0, undefined);
+      table.set(offset + 0, undefined);
+      table.set(offset + 1, null);
+      table.set(offset + 2, true);
+      table.set(offset + 3, false);
+    },
+  };
+  return {
+    __proto__: null,
+    "./deno_jieba_bg.js": import0,
+  };
+}
+
+const SegmenterFinalization = (typeof FinalizationRegistry === "undefined")
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry((ptr) => wasm.__wbg_segmenter_free(ptr, 1));
+const TokenBufferFinalization = (typeof FinalizationRegistry === "undefined")
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry((ptr) => wasm.__wbg_tokenbuffer_free(ptr, 1));
+const TokenStreamFinalization = (typeof FinalizationRegistry === "undefined")
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry((ptr) => wasm.__wbg_tokenstream_free(ptr, 1));
+
+function addToExternrefTable0(obj) {
+  const idx = wasm.__externref_table_alloc();
+  wasm.__wbindgen_externrefs.set(idx, obj);
+  return idx;
+}
+
+function debugString(val) {
+  // primitive types
+  const type = typeof val;
+  if (type == "number" || type == "boolean" || val == null) {
+    return `${val}`;
+  }
+  if (type == "string") {
+    return `"${val}"`;
+  }
+  if (type == "symbol") {
+    const description = val.description;
+    if (description == null) {
+      return "Symbol";
+    } else {
+      return `Symbol(${description})`;
+    }
+  }
+  if (type == "function") {
+    const name = val.name;
+    if (typeof name == "string" && name.length > 0) {
+      return `Function(${name})`;
+    } else {
+      return "Function";
+    }
+  }
+  // objects
+  if (Array.isArray(val)) {
+    const length = val.length;
+    let debug = "[";
+    if (length > 0) {
+      debug += debugString(val[0]);
+    }
+    for (let i = 1; i < length; i++) {
+      debug += ", " + debugString(val[i]);
+    }
+    debug += "]";
+    return debug;
+  }
+  // Test for built-in
+  const builtInMatches = /\[object ([^\]]+)\]/.exec(toString.call(val));
+  let className;
+  if (builtInMatches && builtInMatches.length > 1) {
+    className = builtInMatches[1];
+  } else {
+    // Failed to match the standard '[object ClassName]'
+    return toString.call(val);
+  }
+  if (className == "Object") {
+    // we're a user defined class or Object
+    // JSON.stringify avoids problems with cycles, and is generally much
+    // easier than looping through ownProperties of `val`.
+    try {
+      return "Object(" + JSON.stringify(val) + ")";
+    } catch (_) {
+      return "Object";
+    }
+  }
+  // errors
+  if (val instanceof Error) {
+    return `${val.name}: ${val.message}\n${val.stack}`;
+  }
+  // TODO we could test for more things here, like `Set`s and `Map`s.
+  return className;
+}
+
+function getArrayU32FromWasm0(ptr, len) {
+  ptr = ptr >>> 0;
+  return getUint32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
+
+function getArrayU8FromWasm0(ptr, len) {
+  ptr = ptr >>> 0;
+  return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
+
+let cachedDataViewMemory0 = null;
+function getDataViewMemory0() {
+  if (
+    cachedDataViewMemory0 === null ||
+    cachedDataViewMemory0.buffer.detached === true ||
+    (cachedDataViewMemory0.buffer.detached === undefined &&
+      cachedDataViewMemory0.buffer !== wasm.memory.buffer)
+  ) {
+    cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
+  }
+  return cachedDataViewMemory0;
+}
+
+function getStringFromWasm0(ptr, len) {
+  return decodeText(ptr >>> 0, len);
+}
+
+let cachedUint32ArrayMemory0 = null;
+function getUint32ArrayMemory0() {
+  if (
+    cachedUint32ArrayMemory0 === null ||
+    cachedUint32ArrayMemory0.byteLength === 0
+  ) {
+    cachedUint32ArrayMemory0 = new Uint32Array(wasm.memory.buffer);
+  }
+  return cachedUint32ArrayMemory0;
+}
+
+let cachedUint8ArrayMemory0 = null;
+function getUint8ArrayMemory0() {
+  if (
+    cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0
+  ) {
+    cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
+  }
+  return cachedUint8ArrayMemory0;
+}
+
+function handleError(f, args) {
   try {
-    const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-    const ptr0 = passStringToWasm0(
-      sentence,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len0 = WASM_VECTOR_LEN;
-    const ptr1 = passStringToWasm0(
-      allowed_pos,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
-    const len1 = WASM_VECTOR_LEN;
-    wasm.extract_tags_by_textrank(retptr, ptr0, len0, top_k, ptr1, len1);
-    var r0 = getInt32Memory0()[retptr / 4 + 0];
-    var r1 = getInt32Memory0()[retptr / 4 + 1];
-    return getStringFromWasm0(r0, r1);
-  } finally {
-    wasm.__wbindgen_add_to_stack_pointer(16);
-    wasm.__wbindgen_free(r0, r1);
+    return f.apply(this, args);
+  } catch (e) {
+    const idx = addToExternrefTable0(e);
+    wasm.__wbindgen_exn_store(idx);
+  }
+}
+
+function isLikeNone(x) {
+  return x === undefined || x === null;
+}
+
+function passArray32ToWasm0(arg, malloc) {
+  const ptr = malloc(arg.length * 4, 4) >>> 0;
+  getUint32ArrayMemory0().set(arg, ptr / 4);
+  WASM_VECTOR_LEN = arg.length;
+  return ptr;
+}
+
+function passArray8ToWasm0(arg, malloc) {
+  const ptr = malloc(arg.length * 1, 1) >>> 0;
+  getUint8ArrayMemory0().set(arg, ptr / 1);
+  WASM_VECTOR_LEN = arg.length;
+  return ptr;
+}
+
+function passStringToWasm0(arg, malloc, realloc) {
+  if (realloc === undefined) {
+    const buf = cachedTextEncoder.encode(arg);
+    const ptr = malloc(buf.length, 1) >>> 0;
+    getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);
+    WASM_VECTOR_LEN = buf.length;
+    return ptr;
+  }
+
+  let len = arg.length;
+  let ptr = malloc(len, 1) >>> 0;
+
+  const mem = getUint8ArrayMemory0();
+
+  let offset = 0;
+
+  for (; offset < len; offset++) {
+    const code = arg.charCodeAt(offset);
+    if (code > 0x7F) break;
+    mem[ptr + offset] = code;
   }
+  if (offset !== len) {
+    if (offset !== 0) {
+      arg = arg.slice(offset);
+    }
+    ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
+    const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
+    const ret = cachedTextEncoder.encodeInto(arg, view);
+
+    offset += ret.written;
+    ptr = realloc(ptr, len, offset, 1) >>> 0;
+  }
+
+  WASM_VECTOR_LEN = offset;
+  return ptr;
+}
+
+function takeFromExternrefTable0(idx) {
+  const value = wasm.__wbindgen_externrefs.get(idx);
+  wasm.__externref_table_dealloc(idx);
+  return value;
+}
+
+let cachedTextDecoder = new TextDecoder("utf-8", {
+  ignoreBOM: true,
+  fatal: true,
+});
+cachedTextDecoder.decode();
+function decodeText(ptr, len) {
+  return cachedTextDecoder.decode(
+    getUint8ArrayMemory0().subarray(ptr, ptr + len),
+  );
 }
 
-const imports = {
-  __wbindgen_placeholder__: {},
-};
+const cachedTextEncoder = new TextEncoder();
+
+let WASM_VECTOR_LEN = 0;
+
+const imports = __wbg_get_imports();
 
 const wasm_url = new URL("deno_jieba_bg.wasm", import.meta.url);
 
@@ -388,7 +2084,7 @@ let lastLoadPromise;
  * @param {decompressCallback=} transform
  * @returns {Promise<{
  *   instance: WebAssembly.Instance;
- *   exports: { get_row_separator: typeof get_row_separator; get_col_separator: typeof get_col_separator; load_dict: typeof load_dict; add_word: typeof add_word; suggest_freq: typeof suggest_freq; reset: typeof reset; cut: typeof cut; cut_all: typeof cut_all; cut_for_search: typeof cut_for_search; tag: typeof tag; tokenize: typeof tokenize; extract_tags_by_tfidf: typeof extract_tags_by_tfidf; extract_tags_by_textrank: typeof extract_tags_by_textrank }
+ *   exports: { Segmenter: typeof Segmenter; TokenBuffer: typeof TokenBuffer; TokenStream: typeof TokenStream; add_stop_word: typeof add_stop_word; add_word: typeof add_word; begin: typeof begin; commit: typeof commit; cut: typeof cut; cut_all: typeof cut_all; cut_for_search: typeof cut_for_search; export_dict: typeof export_dict; extract_tags_by_textrank: typeof extract_tags_by_textrank; extract_tags_by_tfidf: typeof extract_tags_by_tfidf; get_normalize_options: typeof get_normalize_options; get_stop_words: typeof get_stop_words; get_textrank_options: typeof get_textrank_options; is_traditional: typeof is_traditional; load_dict: typeof load_dict; load_idf: typeof load_idf; load_snapshot: typeof load_snapshot; load_stop_words: typeof load_stop_words; lookup: typeof lookup; lookup_batch: typeof lookup_batch; normalize: typeof normalize; pinyin: typeof pinyin; pinyin_initials: typeof pinyin_initials; prefix_search: typeof prefix_search; remove_stop_word: typeof remove_stop_word; remove_word: typeof remove_word; remove_words: typeof remove_words; reset: typeof reset; rollback: typeof rollback; set_normalize_options: typeof set_normalize_options; set_stop_words: typeof set_stop_words; set_textrank_options: typeof set_textrank_options; set_traditional: typeof set_traditional; snapshot: typeof snapshot; split_sentences: typeof split_sentences; suggest_freq: typeof suggest_freq; suggest_split_freq: typeof suggest_split_freq; tag: typeof tag; tag_names: typeof tag_names; to_simplified: typeof to_simplified; to_traditional: typeof to_traditional; token_stream: typeof token_stream; tokenize: typeof tokenize; tokenize_batch: typeof tokenize_batch; tokenize_binary: typeof tokenize_binary; tokenize_sentences: typeof tokenize_sentences; tokenize_with_pinyin: typeof tokenize_with_pinyin; tokenize_with_tags: typeof tokenize_with_tags }
  * }>}
  */
 export function instantiateWithInstance(transform) {
@@ -400,8 +2096,10 @@ export function instantiateWithInstance(transform) {
       try {
         const instance = (await instantiateModule(transform)).instance;
         wasm = instance.exports;
-        cachedInt32Memory0 = new Int32Array(wasm.memory.buffer);
-        cachedUint8Memory0 = new Uint8Array(wasm.memory.buffer);
+        cachedDataViewMemory0 = null;
+        cachedUint32ArrayMemory0 = null;
+        cachedUint8ArrayMemory0 = null;
+        wasm.__wbindgen_start();
         instanceWithExports = {
           instance,
           exports: getWasmInstanceExports(),
@@ -417,19 +2115,57 @@ export function instantiateWithInstance(transform) {
 
 function getWasmInstanceExports() {
   return {
-    get_row_separator,
-    get_col_separator,
-    load_dict,
+    Segmenter,
+    TokenBuffer,
+    TokenStream,
+    add_stop_word,
     add_word,
-    suggest_freq,
-    reset,
+    begin,
+    commit,
     cut,
     cut_all,
     cut_for_search,
+    export_dict,
+    extract_tags_by_textrank,
+    extract_tags_by_tfidf,
+    get_normalize_options,
+    get_stop_words,
+    get_textrank_options,
+    is_traditional,
+    load_dict,
+    load_idf,
+    load_snapshot,
+    load_stop_words,
+    lookup,
+    lookup_batch,
+    normalize,
+    pinyin,
+    pinyin_initials,
+    prefix_search,
+    remove_stop_word,
+    remove_word,
+    remove_words,
+    reset,
+    rollback,
+    set_normalize_options,
+    set_stop_words,
+    set_textrank_options,
+    set_traditional,
+    snapshot,
+    split_sentences,
+    suggest_freq,
+    suggest_split_freq,
     tag,
+    tag_names,
+    to_simplified,
+    to_traditional,
+    token_stream,
     tokenize,
-    extract_tags_by_tfidf,
-    extract_tags_by_textrank,
+    tokenize_batch,
+    tokenize_binary,
+    tokenize_sentences,
+    tokenize_with_pinyin,
+    tokenize_with_tags,
   };
 }
 
@@ -1,38 +1,39 @@
 import * as Lib from "./lib/deno_jieba.generated.js";
 
-await Lib.instantiate();
-
-type Fn<A, B> = (x: A) => B;
-
-const List = {
-  map: <A, B>(fn: Fn<A, B>) => (list: A[]) => list.map(fn),
-};
-
-const String = {
-  split: (separator: string) => (text: string) => text.split(separator),
-};
-
-const Split = {
-  Row: String.split(Lib.get_row_separator()),
-  Col: String.split(Lib.get_col_separator()),
-};
+const { instance } = await Lib.instantiateWithInstance();
+const memory = instance.exports.memory as WebAssembly.Memory;
 
 type Algorithm =
   | typeof Lib.extract_tags_by_tfidf
   | typeof Lib.extract_tags_by_textrank;
 const extractTags = (fn: Algorithm) =>
-  (sentence: string, top_k = 20, allowed_pos = []): string[][] =>
-    List.map(Split.Col)(
-      Split.Row(fn(sentence, top_k, allowed_pos.join(Lib.get_col_separator()))),
-    );
+  (sentence: string, top_k = 20, allowed_pos: TagType[] = []): Keyword[] =>
+    fn(sentence, top_k, allowed_pos);
 
-const Tag = ([word, tag]: string[]): Tag => ({ word, tag: tag as TagType });
-
-const Token = ([word, start, end]: string[]): Token => ({
-  word,
-  start: Number(start),
-  end: Number(end),
-});
+type Batch = typeof Lib.tokenize_batch;
+const tokenizeBatchWith = (fn: Batch) =>
+  (
+    documents: string[],
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): Token[][] => {
+    const encoded = fn(
+      documents.join(""),
+      Uint32Array.from(documents, (document) => document.length),
+      tokenize_mode,
+      cut_mode,
+    );
+    let i = 0;
+    return documents.map((document) => {
+      const tokens: Token[] = [];
+      for (let count = encoded[i++]; count > 0; count--) {
+        const start = encoded[i++];
+        const end = encoded[i++];
+        tokens.push({ word: document.slice(start, end), start, end });
+      }
+      return tokens;
+    });
+  };
 
 // ================================================================
 
@@ -58,6 +59,32 @@ export enum TokenizeMode {
   Search = 1,
 }
 
+/**
+ * Unit of the token positions in the source string
+ */
+export enum OffsetUnit {
+  /** unicode characters, default */
+  Char = 0,
+  /** UTF-8 bytes */
+  Utf8 = 1,
+  /** UTF-16 code units, for `String.prototype.slice` */
+  Utf16 = 2,
+}
+
+/**
+ * How pinyin syllables are written
+ */
+export enum PinyinStyle {
+  /** tone marks, as in `háng`, default */
+  Tone = 0,
+  /** tone numbers, none for the neutral tone, as in `hang2` */
+  ToneNumber = 1,
+  /** no tone, as in `hang` */
+  Plain = 2,
+  /** first letter, as in `h` */
+  FirstLetter = 3,
+}
+
 /**
  * Reset word dictionary
  *
@@ -85,8 +112,12 @@ export const addWord = (word: string, freg = -1, tag: TagType | "" = "") =>
 
 /**
  * Load extra dictionary
+ *
+ * A `word -` line, as written by {@link exportDict} with `delta`, removes the
+ * word. Throws when a line is malformed, reporting its line number, text and
+ * the reason. The dictionary is left unchanged in that case.
+ *
  * @param {Uint8Array | string | URL} source
- * @returns {Promise<string>}
  *
  * ## Examples
  *
@@ -95,22 +126,380 @@ export const addWord = (word: string, freg = -1, tag: TagType | "" = "") =>
  *  loadDict('my-dictionary-path');
  * ```
  */
-export const loadDict = (source: Uint8Array | string | URL): string =>
+export const loadDict = (source: Uint8Array | string | URL): void =>
   typeof source === "string" || source instanceof URL
     ? Lib.load_dict(Deno.readFileSync(source))
     : Lib.load_dict(source);
 
 /**
- * Suggest word frequency to force the characters in a word to be joined or splitted
+ * Remove a word and its tag from dictionary
+ *
+ * Each call rebuilds the dictionary, so prefer {@link removeWords} for many
+ * words. To change the tag of a word, remove it and add it again.
+ *
+ * @param {string} word
+ * @returns {boolean} `false` if the word was not in dictionary
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { cut, removeWord } from './mod.ts';
+ * removeWord("长江大桥");
+ * cut("南京市长江大桥");
+ * // ["南京市", "长江", "大桥"]
+ * ```
+ */
+export const removeWord = (word: string): boolean => Lib.remove_word(word);
+
+/**
+ * Remove many words from dictionary at once
+ *
+ * @param {string[]} words
+ * @returns {number} how many of the words were in dictionary
+ */
+export const removeWords = (words: string[]): number =>
+  Lib.remove_words(words);
+
+/**
+ * State of a word in dictionary
+ */
+export interface WordInfo {
+  /** whether the word is in dictionary */
+  exists: boolean;
+  /** frequency, 0 when missing */
+  freq: number;
+  /** word type, empty when missing or untagged */
+  tag: TagType | "";
+}
+
+/**
+ * Look up a word in dictionary
+ *
  * @param {string} word
+ * @returns {WordInfo}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { lookup } from './mod.ts';
+ * lookup("长江大桥");
+ * // { exists: true, freq: 3858, tag: "ns" }
+ * lookup("不存在的词");
+ * // { exists: false, freq: 0, tag: "" }
+ * ```
+ */
+export const lookup = (word: string): WordInfo => Lib.lookup(word);
+
+/**
+ * Look up many words in dictionary at once
+ *
+ * @param {string[]} words
+ * @returns {WordInfo[]}
+ */
+export const lookupBatch = (words: string[]): WordInfo[] =>
+  Lib.lookup_batch(words);
+
+/**
+ * Dictionary word with its frequency and word type
+ */
+export interface DictWord {
+  /** word */
+  word: string;
+  /** frequency */
+  freq: number;
+  /** word type */
+  tag: TagType | "";
+}
+
+/**
+ * List dictionary words starting with a prefix, most frequent first
+ *
+ * A fuzzy search also lists words starting with the prefix after one of its
+ * characters is replaced, deleted or inserted, or two adjacent ones are
+ * swapped, after the exact matches. It scans the whole dictionary, so it is
+ * slower.
+ *
+ * @param {string} prefix
+ * @param {number} limit - maximum number of words, at least 1
+ * @param {boolean} fuzzy - allow one edit
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { prefixSearch } from './mod.ts';
+ * prefixSearch("长江", 3);
+ * // [
+ * //   { word: "长江", freq: 18930, tag: "ns" },
+ * //   { word: "长江大桥", freq: 3858, tag: "ns" },
+ * //   { word: "长江流域", freq: 1098, tag: "ns" },
+ * // ]
+ * ```
+ */
+export const prefixSearch = (
+  prefix: string,
+  limit = 10,
+  fuzzy = false,
+): DictWord[] => Lib.prefix_search(prefix, limit, fuzzy);
+
+/**
+ * Export dictionary in jieba's `word freq tag` format, sorted by word
+ *
+ * The output can be loaded back with {@link loadDict}. A delta starts with a
+ * `word -` line for every removed word, which {@link loadDict} removes again.
+ *
+ * @param {boolean} delta - only the words removed, added or changed since the starting dictionary
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { addWord, exportDict } from './mod.ts';
+ * addWord("中出", 10000, "v");
+ * exportDict(true);
+ * // "中出 10000 vn\n"
+ * ```
+ */
+export const exportDict = (delta = false): string => Lib.export_dict(delta);
+
+/**
+ * Export the whole dictionary, including added words, as a binary snapshot
+ *
+ * A snapshot stores the prefix trie along with the words, so loading it is
+ * faster than building a dictionary, though it is about four times the size of
+ * the {@link exportDict} text. It is checked when loaded, so a truncated or
+ * corrupted file is rejected.
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { addWord, exportSnapshot } from './mod.ts';
+ * addWord("中出", 10000, "v");
+ * Deno.writeFileSync("dict.snapshot", exportSnapshot());
+ * ```
+ */
+export const exportSnapshot = (): Uint8Array => Lib.snapshot();
+
+/**
+ * Replace the dictionary with a snapshot from {@link exportSnapshot}
+ *
+ * When called first, the default dictionary is never built. Throws when the
+ * snapshot is invalid, leaving the dictionary unchanged.
+ *
+ * @param {Uint8Array | string | URL} source
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { loadSnapshot } from './mod.ts';
+ * loadSnapshot("dict.snapshot");
+ * ```
+ */
+export const loadSnapshot = (source: Uint8Array | string | URL): void =>
+  typeof source === "string" || source instanceof URL
+    ? Lib.load_snapshot(Deno.readFileSync(source))
+    : Lib.load_snapshot(source);
+
+/**
+ * Start a transaction around dictionary changes, such as {@link addWord},
+ * {@link loadDict}, {@link removeWords} or {@link reset}, until
+ * {@link commit} or {@link rollback}. Transactions nest.
+ *
+ * The dictionary is copied on its first change within a transaction, and the
+ * copy is kept until the transaction ends.
+ *
+ * @returns {number} how many transactions are open
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { addWord, begin, cut, rollback } from './mod.ts';
+ * begin();
+ * addWord("中出", 10000, "v");
+ * cut("我们中出了一个叛徒");
+ * // ["我们", "中出", "了", "一个", "叛徒"]
+ * rollback();
+ * cut("我们中出了一个叛徒");
+ * // ["我们", "中", "出", "了", "一个", "叛徒"]
+ * ```
+ */
+export const begin = (): number => Lib.begin();
+
+/**
+ * Keep the dictionary changes of the innermost transaction, see {@link begin}
+ *
+ * Throws when no transaction is open.
+ */
+export const commit = (): void => Lib.commit();
+
+/**
+ * Undo the dictionary changes of the innermost transaction, see {@link begin}
+ *
+ * Throws when no transaction is open.
+ */
+export const rollback = (): void => Lib.rollback();
+
+/**
+ * Run a function in a transaction, committed when it returns and rolled back
+ * when it throws, see {@link begin}
+ *
+ * @param {() => T} fn - synchronous function changing the dictionary
+ * @returns {T} result of `fn`
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { loadDict, transaction } from './mod.ts';
+ * transaction(() => {
+ *   loadDict(Deno.readFileSync("a.txt"));
+ *   loadDict(Deno.readFileSync("b.txt"));
+ * });
+ * ```
+ */
+export const transaction = <T>(fn: () => T): T => {
+  Lib.begin();
+  try {
+    const result = fn();
+    Lib.commit();
+    return result;
+  } catch (err) {
+    Lib.rollback();
+    throw err;
+  }
+};
+
+/**
+ * Suggest word frequency to force the characters in a word to be joined or splitted
+ *
+ * A string is a word to keep joined, an array holds the pieces to split a
+ * word into. With `tune` the frequency is also applied to the dictionary, so
+ * later cuts follow it. A split only applies to a word in the dictionary.
+ *
+ * @param {string | string[]} segment - word, or pieces of a word
+ * @param {boolean} tune - apply the frequency
  * @returns {number}
  *
+ * ## Examples
+ *
  * ```ts
- * import { suggestFreq } from './mod.ts';
+ * import { cut, suggestFreq } from './mod.ts';
  * suggestFreq("中出");
+ * // 348
+ * suggestFreq(["中", "将"], true);
+ * // 494
+ * cut("如果放到post中将出错。");
+ * // ["如果", "放到", "post", "中", "将", "出错", "。"]
+ * ```
+ */
+export const suggestFreq = (
+  segment: string | string[],
+  tune = false,
+): number =>
+  typeof segment === "string"
+    ? Lib.suggest_freq(segment, tune)
+    : Lib.suggest_split_freq(segment, tune);
+
+/** Steps of the normalization run before segmentation, all off by default */
+export interface NormalizeOptions {
+  /** Unicode NFKC, like `㈱` to `(株)` or `ｶ` to `カ` */
+  nfkc: boolean;
+  /** full-width ASCII forms and the ideographic space to ASCII */
+  full_width: boolean;
+  /** case folding, `ABC` to `abc` */
+  case_fold: boolean;
+  /** remove zero-width spaces, joiners and byte order marks */
+  zero_width: boolean;
+}
+
+/**
+ * Normalize texts before they are segmented, so `ＡＢＣ` and `abc` are cut
+ * the same way.
+ *
+ * It applies to cutting, tagging, tokenizing and keyword extraction. Words
+ * come out normalized, while token positions still refer to the original
+ * text, so `sentence.slice(start, end)` gives the source of a token.
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { Normalize, tokenize } from './mod.ts';
+ * Normalize.setOptions({ full_width: true, case_fold: true });
+ * Normalize.apply("ＡＢＣ公司");
+ * // "abc公司"
+ * tokenize("ＡＢＣ公司");
+ * // [{ word: "abc", start: 0, end: 3 }, { word: "公司", start: 3, end: 5 }]
+ * ```
+ */
+export const Normalize = {
+  /** Set the normalization steps, missing ones are off */
+  setOptions: (options: Partial<NormalizeOptions>): void =>
+    Lib.set_normalize_options(options),
+  /** Get the current normalization steps */
+  getOptions: (): NormalizeOptions => Lib.get_normalize_options(),
+  /** Normalize a text the way it is before segmentation */
+  apply: (text: string): string => Lib.normalize(text),
+};
+
+/**
+ * Convert Traditional Chinese to Simplified Chinese, character for character.
+ *
+ * Like OpenCC, phrases are converted as a whole before single characters, so
+ * `乾隆` stays while `乾淨` becomes `干净`. The tables are derived from the
+ * transforms of ICU and the words of the default dictionary.
+ *
+ * @param {string} text - Traditional Chinese text
+ * @returns {string}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { toSimplified } from './mod.ts';
+ * toSimplified("乾隆皇帝的頭髮很乾淨");
+ * // "乾隆皇帝的头发很干净"
+ * ```
+ */
+export const toSimplified = (text: string): string => Lib.to_simplified(text);
+
+/**
+ * Convert Simplified Chinese to Traditional Chinese, character for character,
+ * see {@link toSimplified}
+ *
+ * @param {string} text - Simplified Chinese text
+ * @returns {string}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { toTraditional } from './mod.ts';
+ * toTraditional("头发的发展");
+ * // "頭髮的發展"
+ * ```
+ */
+export const toTraditional = (text: string): string =>
+  Lib.to_traditional(text);
+
+/**
+ * Segment Traditional Chinese with the Simplified Chinese dictionary.
+ *
+ * Texts are converted with {@link toSimplified} before they are cut, tagged,
+ * tokenized or searched for keywords, while words, keywords and positions
+ * still refer to the Traditional text. Simplified Chinese is mostly left as
+ * it is.
+ *
+ * @param {boolean} enabled - convert Traditional Chinese
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { cut, setTraditional } from './mod.ts';
+ * setTraditional(true);
+ * cut("我來到北京清華大學");
+ * // ["我", "來到", "北京", "清華大學"]
  * ```
  */
-export const suggestFreq = Lib.suggest_freq;
+export const setTraditional = (enabled: boolean): void =>
+  Lib.set_traditional(enabled);
+
+/** Whether Traditional Chinese is converted, see {@link setTraditional} */
+export const isTraditional = (): boolean => Lib.is_traditional();
 
 /**
  * divide strings into lists of substrings
@@ -147,10 +536,10 @@ export const cut = (
   mode: CutMode = CutMode.Default,
 ): string[] => {
   if (mode === CutMode.All) {
-    return Split.Row(Lib.cut_all(sentence));
+    return Lib.cut_all(sentence);
   }
 
-  return Split.Row(Lib.cut(sentence, mode));
+  return Lib.cut(sentence, mode);
 };
 
 /**
@@ -170,7 +559,7 @@ export const cut = (
 export const cutForSearch = (
   sentence: string,
   mode: CutMode.Default | CutMode.HMM = CutMode.Default,
-): string[] => Split.Row(Lib.cut_for_search(sentence, mode));
+): string[] => Lib.cut_for_search(sentence, mode);
 
 export type TagType =
   | "n" // 普通名词
@@ -227,12 +616,7 @@ export interface Tag {
 export const tag = (
   sentence: string,
   mode: CutMode.Default | CutMode.HMM = CutMode.Default,
-): Tag[] =>
-  List.map(Tag)(
-    List.map(Split.Col)(
-      Split.Row(Lib.tag(sentence, mode)),
-    ),
-  );
+): Tag[] => Lib.tag(sentence, mode);
 
 /**
  * Token group with word token and spans
@@ -240,9 +624,9 @@ export const tag = (
 export interface Token {
   /** token */
   word: string;
-  /** start position of the source string */
+  /** start position of the source string, see {@link OffsetUnit} */
   start: number;
-  /** end position of the source string */
+  /** end position of the source string, see {@link OffsetUnit} */
   end: number;
 }
 
@@ -252,6 +636,7 @@ export interface Token {
  * @param {string} sentence - source string
  * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
  * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
  *
  * ## Examples
  *
@@ -259,23 +644,974 @@ export interface Token {
  * import { tokenize } from './mod.ts';
  *  tokenize("南京市长江大桥");
  * ```
+ *
+ * - positions usable with `String.prototype.slice`
+ * ```ts
+ * import { CutMode, OffsetUnit, tokenize, TokenizeMode } from './mod.ts';
+ * const sentence = "😀南京市长江大桥";
+ * tokenize(sentence, TokenizeMode.Default, CutMode.Default, OffsetUnit.Utf16)
+ *   .map(({ start, end }) => sentence.slice(start, end));
+ * // ["😀", "南京市", "长江大桥"]
+ * ```
  */
 export const tokenize = (
   sentence: string,
   tokenize_mode: TokenizeMode = TokenizeMode.Default,
   cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
-): Token[] =>
-  List.map(Token)(
-    List.map(Split.Col)(
-      Split.Row(
-        Lib.tokenize(sentence, tokenize_mode, cut_mode),
-      ),
-    ),
-  );
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+): Token[] => Lib.tokenize(sentence, tokenize_mode, cut_mode, offset_unit);
 
-export const TFIDF = {
-  extractTags: extractTags(Lib.extract_tags_by_tfidf),
-};
-export const TextRank = {
-  extractTags: extractTags(Lib.extract_tags_by_textrank),
-};
+/**
+ * Tagged token group with word token, word type and spans
+ */
+export interface TaggedToken extends Token {
+  /** word type */
+  tag: TagType;
+}
+
+/**
+ * string tokenization with the word type of every token
+ *
+ * @param {string} sentence - source string
+ * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
+ * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { tokenizeWithTags } from './mod.ts';
+ *  tokenizeWithTags("南京市长江大桥");
+ * // [
+ * //   { word: "南京市", tag: "ns", start: 0, end: 3 },
+ * //   { word: "长江大桥", tag: "ns", start: 3, end: 7 },
+ * // ]
+ * ```
+ */
+export const tokenizeWithTags = (
+  sentence: string,
+  tokenize_mode: TokenizeMode = TokenizeMode.Default,
+  cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+): TaggedToken[] =>
+  Lib.tokenize_with_tags(sentence, tokenize_mode, cut_mode, offset_unit);
+
+/**
+ * Sentence with its span in the source document
+ */
+export interface Sentence {
+  /** sentence text */
+  text: string;
+  /** start position in the document, see {@link OffsetUnit} */
+  start: number;
+  /** end position in the document, see {@link OffsetUnit} */
+  end: number;
+}
+
+/**
+ * split a document into sentences
+ *
+ * A sentence ends after `。！？!?…` and the like, along with the closing quotes
+ * and brackets following them, and at every line break. A `.` only ends a
+ * sentence before whitespace, so numbers like `3.14` stay whole. Surrounding
+ * whitespace is not part of a sentence.
+ *
+ * @param {string} text - source document
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { splitSentences } from './mod.ts';
+ * splitSentences("他说：“你好！”然后走了……真的吗？！");
+ * // [
+ * //   { text: "他说：“你好！”", start: 0, end: 8 },
+ * //   { text: "然后走了……", start: 8, end: 14 },
+ * //   { text: "真的吗？！", start: 14, end: 19 },
+ * // ]
+ * ```
+ */
+export const splitSentences = (
+  text: string,
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+): Sentence[] => Lib.split_sentences(text, offset_unit);
+
+/**
+ * Sentence with its tokens, spans in the source document
+ */
+export interface SentenceTokens extends Sentence {
+  /** tokens of the sentence */
+  tokens: Token[];
+}
+
+/**
+ * split a document into sentences, see {@link splitSentences}, and tokenize
+ * each of them, with positions in the document
+ *
+ * @param {string} text - source document
+ * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
+ * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { tokenizeSentences } from './mod.ts';
+ * tokenizeSentences("南京市长江大桥。我来到北京");
+ * // [
+ * //   {
+ * //     text: "南京市长江大桥。", start: 0, end: 8,
+ * //     tokens: [
+ * //       { word: "南京市", start: 0, end: 3 },
+ * //       { word: "长江大桥", start: 3, end: 7 },
+ * //       { word: "。", start: 7, end: 8 },
+ * //     ],
+ * //   },
+ * //   {
+ * //     text: "我来到北京", start: 8, end: 13,
+ * //     tokens: [
+ * //       { word: "我", start: 8, end: 9 },
+ * //       { word: "来到", start: 9, end: 11 },
+ * //       { word: "北京", start: 11, end: 13 },
+ * //     ],
+ * //   },
+ * // ]
+ * ```
+ */
+export const tokenizeSentences = (
+  text: string,
+  tokenize_mode: TokenizeMode = TokenizeMode.Default,
+  cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+): SentenceTokens[] =>
+  Lib.tokenize_sentences(text, tokenize_mode, cut_mode, offset_unit);
+
+/**
+ * Tokens kept in wasm memory as flat arrays, see {@link tokenizeBinary}
+ *
+ * `offsets` and `tags` are views into wasm memory without copying. Use a view
+ * before the next call into the library, which may move the memory, and
+ * call `free` once done with the tokens.
+ */
+export class TokenBuffer {
+  #buffer: Lib.TokenBuffer;
+  #with_tags: boolean;
+
+  constructor(buffer: Lib.TokenBuffer, with_tags: boolean) {
+    this.#buffer = buffer;
+    this.#with_tags = with_tags;
+  }
+
+  /** number of tokens */
+  get length(): number {
+    return this.#buffer.length;
+  }
+
+  /** `start, end` pair of every token */
+  get offsets(): Uint32Array {
+    return new Uint32Array(
+      memory.buffer,
+      this.#buffer.offsets_ptr(),
+      this.length * 2,
+    );
+  }
+
+  /** tag id of every token, empty unless requested, see {@link tagNames} */
+  get tags(): Uint32Array {
+    if (!this.#with_tags) return new Uint32Array(0);
+    return new Uint32Array(memory.buffer, this.#buffer.tags_ptr(), this.length);
+  }
+
+  /** release the tokens */
+  free() {
+    this.#buffer.free();
+  }
+}
+
+/**
+ * string tokenization into flat arrays, without one object per token
+ *
+ * @param {string} sentence - source string
+ * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
+ * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
+ * @param {boolean} with_tags - also return the tag id of every token
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { tagNames, tokenizeBinary } from './mod.ts';
+ * const tokens = tokenizeBinary("南京市长江大桥", 0, 0, 0, true);
+ * tokens.offsets;
+ * // Uint32Array [0, 3, 3, 7]
+ * Array.from(tokens.tags, (id) => tagNames()[id]);
+ * // ["ns", "ns"]
+ * tokens.free();
+ * ```
+ */
+export const tokenizeBinary = (
+  sentence: string,
+  tokenize_mode: TokenizeMode = TokenizeMode.Default,
+  cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+  with_tags = false,
+): TokenBuffer =>
+  new TokenBuffer(
+    Lib.tokenize_binary(
+      sentence,
+      tokenize_mode,
+      cut_mode,
+      offset_unit,
+      with_tags,
+    ),
+    with_tags,
+  );
+
+/**
+ * names of the tag ids returned by {@link tokenizeBinary}, indexed by id
+ */
+export const tagNames = (): TagType[] => Lib.tag_names();
+
+/**
+ * Tokenizer for a text pushed in chunks, see {@link tokenStream}
+ *
+ * Text after the last punctuation or whitespace is kept until the next chunk,
+ * so words split across chunks come out whole. Call `free` once done.
+ */
+export class TokenStream {
+  #stream: Lib.TokenStream;
+  #decoder = new TextDecoder();
+
+  constructor(stream: Lib.TokenStream) {
+    this.#stream = stream;
+  }
+
+  /**
+   * add a chunk of text, return the tokens completed by it
+   *
+   * Bytes are decoded as UTF-8, a character may be split across chunks.
+   */
+  push(chunk: string | Uint8Array): Token[] {
+    return this.#stream.push(
+      typeof chunk === "string"
+        ? chunk
+        : this.#decoder.decode(chunk, { stream: true }),
+    );
+  }
+
+  /** tokenize the rest of the text, the stream then starts over */
+  finish(): Token[] {
+    const tokens: Token[] = this.#stream.push(this.#decoder.decode());
+    return tokens.concat(this.#stream.finish());
+  }
+
+  /** tokenize a whole readable stream, such as the `readable` of a file */
+  async *tokenize(
+    readable: AsyncIterable<string | Uint8Array>,
+  ): AsyncGenerator<Token> {
+    for await (const chunk of readable) {
+      yield* this.push(chunk);
+    }
+    yield* this.finish();
+  }
+
+  /** release the stream */
+  free() {
+    this.#stream.free();
+  }
+}
+
+/**
+ * tokenization of a text pushed in chunks, with positions in the whole text
+ *
+ * Tokens come out as soon as no later chunk can change them, the same as
+ * {@link tokenize} on the whole text. The stream keeps the dictionary as it
+ * was when the stream was created.
+ *
+ * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
+ * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { tokenStream } from './mod.ts';
+ * const stream = tokenStream();
+ * stream.push("我来到北京，清华");
+ * // [
+ * //   { word: "我", start: 0, end: 1 },
+ * //   { word: "来到", start: 1, end: 3 },
+ * //   { word: "北京", start: 3, end: 5 },
+ * //   { word: "，", start: 5, end: 6 },
+ * // ]
+ * stream.push("大学。他说");
+ * // [{ word: "清华大学", start: 6, end: 10 }, { word: "。", start: 10, end: 11 }]
+ * stream.finish();
+ * // [{ word: "他", start: 11, end: 12 }, { word: "说", start: 12, end: 13 }]
+ * stream.free();
+ * ```
+ *
+ * - tokenize a large file
+ * ```ts
+ * import { tokenStream } from './mod.ts';
+ * const stream = tokenStream();
+ * const file = await Deno.open("novel.txt");
+ * for await (const token of stream.tokenize(file.readable)) {
+ *   console.log(token.word);
+ * }
+ * stream.free();
+ * ```
+ */
+export const tokenStream = (
+  tokenize_mode: TokenizeMode = TokenizeMode.Default,
+  cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+): TokenStream =>
+  new TokenStream(Lib.token_stream(tokenize_mode, cut_mode, offset_unit));
+
+/**
+ * string tokenization of many documents in one call
+ *
+ * Token positions are UTF-16 code units, see {@link OffsetUnit.Utf16}.
+ *
+ * @param {string[]} documents - source strings
+ * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
+ * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { tokenizeBatch } from './mod.ts';
+ *  tokenizeBatch(["南京市长江大桥", "我来到北京清华大学"]);
+ * ```
+ */
+export const tokenizeBatch = tokenizeBatchWith(Lib.tokenize_batch);
+
+/**
+ * divide many strings into lists of substrings in one call
+ *
+ * @param {string[]} documents - source strings
+ * @param {CutMode.Default | CutMode.HMM} mode - {@link CutMode}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { cutBatch } from './mod.ts';
+ *  cutBatch(["南京市长江大桥", "我来到北京清华大学"]);
+ * // [["南京市", "长江大桥"], ["我", "来到", "北京", "清华大学"]]
+ * ```
+ */
+export const cutBatch = (
+  documents: string[],
+  mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+): string[][] =>
+  tokenizeBatch(documents, TokenizeMode.Default, mode)
+    .map((tokens) => tokens.map(({ word }) => word));
+
+/**
+ * Word with its pinyin
+ */
+export interface WordPinyin {
+  /** word */
+  word: string;
+  /** one syllable per character, other characters as they are */
+  pinyin: string[];
+}
+
+/**
+ * divide a string into words with their pinyin
+ *
+ * Polyphonic characters are read as they are in the word, as the `行` of
+ * `银行` and `行走`. Tones are the ones of the dictionary, without tone
+ * sandhi.
+ *
+ * @param {string} sentence - source string
+ * @param {PinyinStyle} style - {@link PinyinStyle}
+ * @param {CutMode.Default | CutMode.HMM} mode - {@link CutMode}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { pinyin } from './mod.ts';
+ *  pinyin("去银行");
+ * // [
+ * //   { word: "去", pinyin: ["qù"] },
+ * //   { word: "银行", pinyin: ["yín", "háng"] },
+ * // ]
+ * ```
+ */
+export const pinyin = (
+  sentence: string,
+  style: PinyinStyle = PinyinStyle.Tone,
+  mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+): WordPinyin[] => Lib.pinyin(sentence, style, mode);
+
+/**
+ * Token group with word token, spans and pinyin
+ */
+export interface PinyinToken extends Token {
+  /** one syllable per character, other characters as they are */
+  pinyin: string[];
+}
+
+/**
+ * string tokenization with the pinyin of every token, see {@link pinyin}
+ *
+ * @param {string} sentence - source string
+ * @param {PinyinStyle} style - {@link PinyinStyle}
+ * @param {TokenizeMode} tokenize_mode - {@link TokenizeMode}
+ * @param {CutMode.Default | CutMode.HMM} cut_mode - {@link CutMode}
+ * @param {OffsetUnit} offset_unit - {@link OffsetUnit}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { PinyinStyle, tokenizeWithPinyin } from './mod.ts';
+ *  tokenizeWithPinyin("在路上行走", PinyinStyle.ToneNumber);
+ * // [
+ * //   { word: "在", start: 0, end: 1, pinyin: ["zai4"] },
+ * //   { word: "路上", start: 1, end: 3, pinyin: ["lu4", "shang4"] },
+ * //   { word: "行走", start: 3, end: 5, pinyin: ["xing2", "zou3"] },
+ * // ]
+ * ```
+ */
+export const tokenizeWithPinyin = (
+  sentence: string,
+  style: PinyinStyle = PinyinStyle.Tone,
+  tokenize_mode: TokenizeMode = TokenizeMode.Default,
+  cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  offset_unit: OffsetUnit = OffsetUnit.Char,
+): PinyinToken[] =>
+  Lib.tokenize_with_pinyin(
+    sentence,
+    tokenize_mode,
+    cut_mode,
+    offset_unit,
+    style,
+  );
+
+/**
+ * abbreviate a string to the first letter of the pinyin of every
+ * character, for search by initials. Other letters and digits are kept,
+ * other characters dropped.
+ *
+ * @param {string} sentence - source string
+ * @param {CutMode.Default | CutMode.HMM} mode - {@link CutMode}
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { pinyinInitials } from './mod.ts';
+ *  pinyinInitials("中国银行");
+ * // "zgyh"
+ * ```
+ */
+export const pinyinInitials = (
+  sentence: string,
+  mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+): string => Lib.pinyin_initials(sentence, mode);
+
+/**
+ * Add a stop word ignored by {@link TFIDF} and {@link TextRank}
+ * @returns {boolean} `false` if the word was already a stop word
+ *
+ * ```ts
+ * import { addStopWord } from './mod.ts';
+ * addStopWord("纽约");
+ * ```
+ */
+export const addStopWord = (word: string): boolean => Lib.add_stop_word(word);
+
+/**
+ * Remove a stop word
+ * @returns {boolean} `false` if the word was not a stop word
+ */
+export const removeStopWord = (word: string): boolean =>
+  Lib.remove_stop_word(word);
+
+/**
+ * Replace all stop words
+ *
+ * ```ts
+ * import { setStopWords } from './mod.ts';
+ * setStopWords(["的", "了", "是"]);
+ * ```
+ */
+export const setStopWords = (words: string[]): void =>
+  Lib.set_stop_words(words);
+
+/**
+ * Load extra stop words from a file with one word per line
+ * @param {Uint8Array | string | URL} source
+ */
+export const loadStopWords = (source: Uint8Array | string | URL): void =>
+  typeof source === "string" || source instanceof URL
+    ? Lib.load_stop_words(Deno.readFileSync(source))
+    : Lib.load_stop_words(source);
+
+/** List the current stop words in sorted order */
+export const getStopWords = (): string[] => Lib.get_stop_words();
+
+/**
+ * Load a custom IDF table in `word idf` format, merged over the default one
+ * and used by {@link TFIDF}. Builds without the `default-idf` feature have no
+ * default table, so TF-IDF finds no keywords until one is loaded.
+ *
+ * Throws when a line is malformed, reporting its line number, text and the
+ * reason. The table is left unchanged in that case.
+ *
+ * @param {Uint8Array | string | URL} source
+ *
+ * ```ts
+ * import { loadIdf } from './mod.ts';
+ * loadIdf('my-idf-path');
+ * ```
+ */
+export const loadIdf = (source: Uint8Array | string | URL): void =>
+  typeof source === "string" || source instanceof URL
+    ? Lib.load_idf(Deno.readFileSync(source))
+    : Lib.load_idf(source);
+
+/** Keyword with its weight */
+export interface Keyword {
+  /** keyword */
+  keyword: string;
+  /** weight of the keyword */
+  weight: number;
+}
+
+export const TFIDF = {
+  extractTags: extractTags(Lib.extract_tags_by_tfidf),
+};
+/** Parameters of TextRank keyword extraction */
+export interface TextRankOptions {
+  /** size of the co-occurrence window, in words, default `5` */
+  span: number;
+  /** probability of following a co-occurrence edge, default `0.85` */
+  damping_factor: number;
+  /** stop iterating once no rank changes by more than this, default `0` */
+  tolerance: number;
+  /** upper bound on the number of iterations, default `20` */
+  max_iterations: number;
+}
+
+export const TextRank = {
+  extractTags: extractTags(Lib.extract_tags_by_textrank),
+  /** Set the TextRank parameters, missing fields fall back to their defaults */
+  setOptions: (options: Partial<TextRankOptions>): void =>
+    Lib.set_textrank_options(options),
+  /** Get the current TextRank parameters */
+  getOptions: (): TextRankOptions => Lib.get_textrank_options(),
+};
+
+/**
+ * Independent Jieba instance with its own dictionary.
+ *
+ * Words added to one instance never leak into another instance or into
+ * the module level functions. Call {@link Jieba.free} once the instance
+ * is no longer needed to release its wasm memory.
+ *
+ * ## Examples
+ *
+ * ```ts
+ * import { Jieba } from './mod.ts';
+ * const jieba = new Jieba();
+ * jieba.addWord("中出", 10000, "v");
+ * jieba.cut("我们中出了一个叛徒");
+ * // ["我们", "中出", "了", "一个", "叛徒"]
+ * jieba.free();
+ * ```
+ */
+export class Jieba {
+  #segmenter: Lib.Segmenter;
+
+  /** create an instance with the default dictionary */
+  constructor(segmenter: Lib.Segmenter = new Lib.Segmenter()) {
+    this.#segmenter = segmenter;
+  }
+
+  /** create an instance with an empty dictionary */
+  static empty(): Jieba {
+    return new Jieba(Lib.Segmenter.empty());
+  }
+
+  /**
+   * create an instance from a dictionary instead of the default one, throws
+   * when a line is malformed like {@link loadDict}
+   */
+  static withDict(source: Uint8Array | string | URL): Jieba {
+    return new Jieba(
+      Lib.Segmenter.with_dict(
+        typeof source === "string" || source instanceof URL
+          ? Deno.readFileSync(source)
+          : source,
+      ),
+    );
+  }
+
+  /**
+   * create an instance from a snapshot, see {@link exportSnapshot}, without
+   * building the default dictionary
+   */
+  static fromSnapshot(source: Uint8Array | string | URL): Jieba {
+    return new Jieba(
+      Lib.Segmenter.from_snapshot(
+        typeof source === "string" || source instanceof URL
+          ? Deno.readFileSync(source)
+          : source,
+      ),
+    );
+  }
+
+  /**
+   * Reset word dictionary of this instance to the one it started with: the
+   * default dictionary, no words for {@link Jieba.empty}, or the words given
+   * to {@link Jieba.withDict} or {@link Jieba.fromSnapshot}
+   */
+  reset() {
+    this.#segmenter.reset();
+  }
+
+  /** add word in dictionary of this instance */
+  addWord(word: string, freg = -1, tag: TagType | "" = "") {
+    return this.#segmenter.add_word(word, freg, tag);
+  }
+
+  /** Load extra dictionary into this instance */
+  loadDict(source: Uint8Array | string | URL): void {
+    return typeof source === "string" || source instanceof URL
+      ? this.#segmenter.load_dict(Deno.readFileSync(source))
+      : this.#segmenter.load_dict(source);
+  }
+
+  /** Remove a word from dictionary of this instance, see {@link removeWord} */
+  removeWord(word: string): boolean {
+    return this.#segmenter.remove_word(word);
+  }
+
+  /** Remove many words from dictionary of this instance at once */
+  removeWords(words: string[]): number {
+    return this.#segmenter.remove_words(words);
+  }
+
+  /** Look up a word in dictionary of this instance, see {@link lookup} */
+  lookup(word: string): WordInfo {
+    return this.#segmenter.lookup(word);
+  }
+
+  /** Look up many words in dictionary of this instance at once */
+  lookupBatch(words: string[]): WordInfo[] {
+    return this.#segmenter.lookup_batch(words);
+  }
+
+  /** List words of this instance by prefix, see {@link prefixSearch} */
+  prefixSearch(prefix: string, limit = 10, fuzzy = false): DictWord[] {
+    return this.#segmenter.prefix_search(prefix, limit, fuzzy);
+  }
+
+  /** Export dictionary of this instance as text, see {@link exportDict} */
+  exportDict(delta = false): string {
+    return this.#segmenter.export_dict(delta);
+  }
+
+  /** Export the dictionary of this instance, see {@link exportSnapshot} */
+  exportSnapshot(): Uint8Array {
+    return this.#segmenter.snapshot();
+  }
+
+  /** Replace the dictionary of this instance, see {@link loadSnapshot} */
+  loadSnapshot(source: Uint8Array | string | URL): void {
+    return typeof source === "string" || source instanceof URL
+      ? this.#segmenter.load_snapshot(Deno.readFileSync(source))
+      : this.#segmenter.load_snapshot(source);
+  }
+
+  /** Start a transaction around dictionary changes, see {@link begin} */
+  begin(): number {
+    return this.#segmenter.begin();
+  }
+
+  /** Keep the changes of the innermost transaction, see {@link commit} */
+  commit(): void {
+    this.#segmenter.commit();
+  }
+
+  /** Undo the changes of the innermost transaction, see {@link rollback} */
+  rollback(): void {
+    this.#segmenter.rollback();
+  }
+
+  /** Run a function in a transaction, see {@link transaction} */
+  transaction<T>(fn: () => T): T {
+    this.begin();
+    try {
+      const result = fn();
+      this.commit();
+      return result;
+    } catch (err) {
+      this.rollback();
+      throw err;
+    }
+  }
+
+  /** Suggest word frequency to force the characters in a word to be joined or splitted */
+  suggestFreq(segment: string | string[], tune = false): number {
+    return typeof segment === "string"
+      ? this.#segmenter.suggest_freq(segment, tune)
+      : this.#segmenter.suggest_split_freq(segment, tune);
+  }
+
+  /** normalize texts before they are segmented, see {@link Normalize} */
+  get Normalize() {
+    const segmenter = this.#segmenter;
+    return {
+      setOptions: (options: Partial<NormalizeOptions>): void =>
+        segmenter.set_normalize_options(options),
+      getOptions: (): NormalizeOptions => segmenter.get_normalize_options(),
+      apply: (text: string): string => segmenter.normalize(text),
+    };
+  }
+
+  /** Segment Traditional Chinese, see {@link setTraditional} */
+  setTraditional(enabled: boolean): void {
+    this.#segmenter.set_traditional(enabled);
+  }
+
+  /** Whether Traditional Chinese is converted, see {@link setTraditional} */
+  isTraditional(): boolean {
+    return this.#segmenter.is_traditional();
+  }
+
+  /** divide strings into lists of substrings, see {@link cut} */
+  cut(sentence: string, mode: CutMode = CutMode.Default): string[] {
+    if (mode === CutMode.All) {
+      return this.#segmenter.cut_all(sentence);
+    }
+
+    return this.#segmenter.cut(sentence, mode);
+  }
+
+  /** divide strings into lists of substrings, for search engine, see {@link cutForSearch} */
+  cutForSearch(
+    sentence: string,
+    mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): string[] {
+    return this.#segmenter.cut_for_search(sentence, mode);
+  }
+
+  /** extract tags from source string, see {@link tag} */
+  tag(
+    sentence: string,
+    mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): Tag[] {
+    return this.#segmenter.tag(sentence, mode);
+  }
+
+  /** string tokenization, see {@link tokenize} */
+  tokenize(
+    sentence: string,
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+  ): Token[] {
+    return this.#segmenter.tokenize(
+      sentence,
+      tokenize_mode,
+      cut_mode,
+      offset_unit,
+    );
+  }
+
+  /** string tokenization with word types, see {@link tokenizeWithTags} */
+  tokenizeWithTags(
+    sentence: string,
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+  ): TaggedToken[] {
+    return this.#segmenter.tokenize_with_tags(
+      sentence,
+      tokenize_mode,
+      cut_mode,
+      offset_unit,
+    );
+  }
+
+  /** split a document into sentences, see {@link splitSentences} */
+  splitSentences(
+    text: string,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+  ): Sentence[] {
+    return this.#segmenter.split_sentences(text, offset_unit);
+  }
+
+  /** tokenize a document sentence by sentence, see {@link tokenizeSentences} */
+  tokenizeSentences(
+    text: string,
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+  ): SentenceTokens[] {
+    return this.#segmenter.tokenize_sentences(
+      text,
+      tokenize_mode,
+      cut_mode,
+      offset_unit,
+    );
+  }
+
+  /** string tokenization into flat arrays, see {@link tokenizeBinary} */
+  tokenizeBinary(
+    sentence: string,
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+    with_tags = false,
+  ): TokenBuffer {
+    return new TokenBuffer(
+      this.#segmenter.tokenize_binary(
+        sentence,
+        tokenize_mode,
+        cut_mode,
+        offset_unit,
+        with_tags,
+      ),
+      with_tags,
+    );
+  }
+
+  /** names of the tag ids of {@link Jieba.tokenizeBinary} */
+  tagNames(): TagType[] {
+    return this.#segmenter.tag_names();
+  }
+
+  /** tokenization of a text pushed in chunks, see {@link tokenStream} */
+  tokenStream(
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+  ): TokenStream {
+    return new TokenStream(
+      this.#segmenter.token_stream(tokenize_mode, cut_mode, offset_unit),
+    );
+  }
+
+  /** string tokenization of many documents, see {@link tokenizeBatch} */
+  tokenizeBatch(
+    documents: string[],
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): Token[][] {
+    const segmenter = this.#segmenter;
+    return tokenizeBatchWith(segmenter.tokenize_batch.bind(segmenter))(
+      documents,
+      tokenize_mode,
+      cut_mode,
+    );
+  }
+
+  /** divide many strings into lists of substrings, see {@link cutBatch} */
+  cutBatch(
+    documents: string[],
+    mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): string[][] {
+    return this.tokenizeBatch(documents, TokenizeMode.Default, mode)
+      .map((tokens) => tokens.map(({ word }) => word));
+  }
+
+  /** divide a string into words with their pinyin, see {@link pinyin} */
+  pinyin(
+    sentence: string,
+    style: PinyinStyle = PinyinStyle.Tone,
+    mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): WordPinyin[] {
+    return this.#segmenter.pinyin(sentence, style, mode);
+  }
+
+  /** string tokenization with pinyin, see {@link tokenizeWithPinyin} */
+  tokenizeWithPinyin(
+    sentence: string,
+    style: PinyinStyle = PinyinStyle.Tone,
+    tokenize_mode: TokenizeMode = TokenizeMode.Default,
+    cut_mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+    offset_unit: OffsetUnit = OffsetUnit.Char,
+  ): PinyinToken[] {
+    return this.#segmenter.tokenize_with_pinyin(
+      sentence,
+      tokenize_mode,
+      cut_mode,
+      offset_unit,
+      style,
+    );
+  }
+
+  /** first letters of the pinyin of a string, see {@link pinyinInitials} */
+  pinyinInitials(
+    sentence: string,
+    mode: CutMode.Default | CutMode.HMM = CutMode.Default,
+  ): string {
+    return this.#segmenter.pinyin_initials(sentence, mode);
+  }
+
+  /** Add a stop word ignored by keyword extraction of this instance */
+  addStopWord(word: string): boolean {
+    return this.#segmenter.add_stop_word(word);
+  }
+
+  /** Remove a stop word of this instance */
+  removeStopWord(word: string): boolean {
+    return this.#segmenter.remove_stop_word(word);
+  }
+
+  /** Replace all stop words of this instance */
+  setStopWords(words: string[]): void {
+    this.#segmenter.set_stop_words(words);
+  }
+
+  /** Load extra stop words into this instance */
+  loadStopWords(source: Uint8Array | string | URL): void {
+    typeof source === "string" || source instanceof URL
+      ? this.#segmenter.load_stop_words(Deno.readFileSync(source))
+      : this.#segmenter.load_stop_words(source);
+  }
+
+  /** List the current stop words of this instance */
+  getStopWords(): string[] {
+    return this.#segmenter.get_stop_words();
+  }
+
+  /** Load a custom IDF table into this instance, see {@link loadIdf} */
+  loadIdf(source: Uint8Array | string | URL): void {
+    typeof source === "string" || source instanceof URL
+      ? this.#segmenter.load_idf(Deno.readFileSync(source))
+      : this.#segmenter.load_idf(source);
+  }
+
+  /** extract keywords by TF-IDF */
+  get TFIDF() {
+    const segmenter = this.#segmenter;
+    return {
+      extractTags: extractTags(
+        segmenter.extract_tags_by_tfidf.bind(segmenter),
+      ),
+    };
+  }
+
+  /** extract keywords by TextRank */
+  get TextRank() {
+    const segmenter = this.#segmenter;
+    return {
+      extractTags: extractTags(
+        segmenter.extract_tags_by_textrank.bind(segmenter),
+      ),
+      setOptions: (options: Partial<TextRankOptions>): void =>
+        segmenter.set_textrank_options(options),
+      getOptions: (): TextRankOptions => segmenter.get_textrank_options(),
+    };
+  }
+
+  /** Release the wasm memory held by this instance */
+  free() {
+    this.#segmenter.free();
+  }
+}
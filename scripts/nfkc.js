@@ -0,0 +1,34 @@
+// Generate the NFKC tables of src/normalize.rs from the Unicode data of the
+// JavaScript runtime:
+//
+//   deno run --allow-write scripts/nfkc.js
+//
+// `src/data/nfkc.txt` maps every character NFKC changes on its own to its
+// normalized form, `src/data/compose.txt` lists the pairs canonical
+// composition joins into one character, except Hangul syllables.
+
+const hex = (text) =>
+  Array.from(text, (c) => c.codePointAt(0).toString(16).toUpperCase())
+    .join(" ");
+
+const mappings = [];
+const pairs = [];
+for (let code = 0; code <= 0x10ffff; code++) {
+  if (code >= 0xd800 && code <= 0xdfff) continue;
+  if (code >= 0xac00 && code <= 0xd7a3) continue;
+  const c = String.fromCodePoint(code);
+
+  const nfkc = c.normalize("NFKC");
+  if (nfkc !== c) mappings.push(`${hex(c)} ${hex(nfkc)}`);
+
+  const decomposed = Array.from(c.normalize("NFD"));
+  if (decomposed.length < 2 || c.normalize("NFC") !== c) continue;
+  const first = decomposed.slice(0, -1).join("").normalize("NFC");
+  const last = decomposed[decomposed.length - 1];
+  if (Array.from(first).length === 1 && (first + last).normalize("NFC") === c) {
+    pairs.push(`${hex(first)} ${hex(last)} ${hex(c)}`);
+  }
+}
+
+Deno.writeTextFileSync("src/data/nfkc.txt", mappings.join("\n") + "\n");
+Deno.writeTextFileSync("src/data/compose.txt", pairs.join("\n") + "\n");
@@ -0,0 +1,81 @@
+// Generate the conversion tables of src/convert.rs from the Simplified and
+// Traditional Chinese transforms of ICU, which has `uconv` run them:
+//
+//   deno run --allow-read --allow-write --allow-run scripts/opencc.js
+//
+// Like OpenCC, every table lists characters and then the phrases converted
+// differently than the shorter entries would, as `from<TAB>to` lines.
+// Phrases are the words of the default dictionary in either script. Only
+// conversions keeping the number of characters are kept, so positions in a
+// converted text are the same as in the original.
+
+const isHan = (c) => /\p{Script=Han}/u.test(c);
+
+const transform = (id, texts) => {
+  const { stdout } = new Deno.Command("uconv", {
+    args: ["-x", id],
+    stdin: "piped",
+    stdout: "piped",
+  }).outputSync({ input: texts.join("\n") + "\n" });
+  return new TextDecoder().decode(stdout).split("\n").slice(0, texts.length);
+};
+
+const chars = [];
+for (let code = 0x3400; code <= 0x2fa1f; code++) {
+  const c = String.fromCodePoint(code);
+  if (isHan(c)) chars.push(c);
+}
+
+// words of the default dictionary embedded by the `default-dict` feature, most
+// frequent first
+const words = Deno.readTextFileSync("vendor/jieba-rs/src/data/dict.txt")
+  .split("\n")
+  .map((line) => line.split(" "))
+  .filter(([word]) => Array.from(word).length > 1 && Array.from(word).every(isHan))
+  .sort((a, b) => Number(b[1]) - Number(a[1]))
+  .map(([word]) => word);
+
+// Convert like src/convert.rs, taking the longest phrase at every position
+const convert = (mapping, longest, text) => {
+  const chars = Array.from(text);
+  let converted = "";
+  for (let i = 0; i < chars.length;) {
+    let n = Math.min(longest, chars.length - i);
+    for (; n > 1 && !mapping.has(chars.slice(i, i + n).join("")); n--);
+    const from = chars.slice(i, i + n).join("");
+    converted += mapping.get(from) ?? from;
+    i += n;
+  }
+  return converted;
+};
+
+// `phrases` maps every phrase to what it converts to
+const table = (id, phrases) => {
+  const mapping = new Map();
+  transform(id, chars).forEach((to, i) => {
+    if (to !== chars[i] && Array.from(to).length === 1) mapping.set(chars[i], to);
+  });
+
+  // shorter phrases first, so longer ones are only kept when still needed
+  const sorted = [...phrases]
+    .filter(([from, to]) => Array.from(from).length === Array.from(to).length)
+    .sort(([a], [b]) => Array.from(a).length - Array.from(b).length);
+  let longest = 1;
+  for (const [from, to] of sorted) {
+    if (convert(mapping, longest, from) !== to) {
+      mapping.set(from, to);
+      longest = Math.max(longest, Array.from(from).length);
+    }
+  }
+  return [...mapping].map(([from, to]) => `${from}\t${to}`);
+};
+
+// ICU converts words to Traditional Chinese, and the Traditional forms back
+// to the most frequent word with that form
+const traditional = transform("Hans-Hant", words);
+const s2t = new Map(words.map((word, i) => [word, traditional[i]]));
+const t2s = new Map();
+traditional.forEach((form, i) => t2s.has(form) || t2s.set(form, words[i]));
+
+Deno.writeTextFileSync("src/data/s2t.txt", table("Hans-Hant", s2t).join("\n") + "\n");
+Deno.writeTextFileSync("src/data/t2s.txt", table("Hant-Hans", t2s).join("\n") + "\n");
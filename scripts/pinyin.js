@@ -0,0 +1,1019 @@
+// Generate the pinyin tables of src/pinyin.rs from the Han to Latin
+// transform of ICU, which has `uconv` run it:
+//
+//   deno run --allow-read --allow-write --allow-run scripts/pinyin.js
+//
+// ICU gives every character a single reading, which is its own most common
+// reading for most characters but not for all polyphonic ones, corrected by
+// `CHARS`. Readings depending on the word a character is part of are listed
+// in `WORDS`, words of a single character only applying to whole tokens.
+// Readings are written with tone numbers, without one for the neutral tone.
+
+const CHARS = `
+长 chang2  地 di4  为 wei2  兴 xing1  尽 jin4  似 si4  曲 qu3  弹 tan2
+晃 huang4  泊 bo2  倒 dao3  佛 fo2  哄 hong3  铺 pu1  豁 huo4  嚼 jiao2
+咳 ke2  勒 le4  漂 piao1  仆 pu2  茄 qie2  切 qie1  沓 ta4  拓 tuo4
+爪 zhua3  挣 zheng4  缝 feng2  脯 fu3  杆 gan3  拗 ao4  卜 bu3  匙 chi2
+伺 si4  莞 wan3  子 zi3  著 zhu4  裳 shang2
+`;
+
+const WORDS = `
+地 de  得 de
+
+银行 yin2 hang2  行业 hang2 ye4  行列 hang2 lie4  行情 hang2 qing2
+行家 hang2 jia  行长 hang2 zhang3  同行 tong2 hang2  内行 nei4 hang2
+外行 wai4 hang2  商行 shang1 hang2  排行 pai2 hang2  排行榜 pai2 hang2 bang3
+行当 hang2 dang  行话 hang2 hua4  行规 hang2 gui1  分行 fen1 hang2
+总行 zong3 hang2  央行 yang1 hang2  投行 tou2 hang2  行伍 hang2 wu3
+行距 hang2 ju4  洋行 yang2 hang2  琴行 qin2 hang2  车行 che1 hang2
+各行各业 ge4 hang2 ge4 ye4  行市 hang2 shi4  本行 ben3 hang2
+改行 gai3 hang2  转行 zhuan3 hang2  隔行 ge2 hang2
+
+长大 zhang3 da4  成长 cheng2 zhang3  生长 sheng1 zhang3  增长 zeng1 zhang3
+校长 xiao4 zhang3  班长 ban1 zhang3  部长 bu4 zhang3  市长 shi4 zhang3
+省长 sheng3 zhang3  县长 xian4 zhang3  局长 ju2 zhang3  院长 yuan4 zhang3
+厂长 chang3 zhang3  董事长 dong3 shi4 zhang3  家长 jia1 zhang3
+兄长 xiong1 zhang3  首长 shou3 zhang3  团长 tuan2 zhang3  队长 dui4 zhang3
+组长 zu3 zhang3  科长 ke1 zhang3  处长 chu4 zhang3  社长 she4 zhang3
+会长 hui4 zhang3  司长 si1 zhang3  州长 zhou1 zhang3  镇长 zhen4 zhang3
+村长 cun1 zhang3  乡长 xiang1 zhang3  师长 shi1 zhang3  连长 lian2 zhang3
+排长 pai2 zhang3  营长 ying2 zhang3  长老 zhang3 lao3  长辈 zhang3 bei4
+长子 zhang3 zi3  长女 zhang3 nü3  长孙 zhang3 sun1  助长 zhu4 zhang3
+滋长 zi1 zhang3  长进 zhang3 jin4  长相 zhang3 xiang4  年长 nian2 zhang3
+署长 shu3 zhang3  船长 chuan2 zhang3  机长 ji1 zhang3  秘书长 mi4 shu1 zhang3
+委员长 wei3 yuan2 zhang3  所长 suo3 zhang3  馆长 guan3 zhang3
+站长 zhan4 zhang3  台长 tai2 zhang3  检察长 jian3 cha2 zhang3
+厅长 ting1 zhang3  总长 zong3 zhang3  警长 jing3 zhang3  酋长 qiu2 zhang3
+族长 zu2 zhang3  审判长 shen3 pan4 zhang3  庭长 ting2 zhang3
+列车长 lie4 che1 zhang3  舰长 jian4 zhang3
+学长 xue2 zhang3  尊长 zun1 zhang3  长个子 zhang3 ge4 zi
+拔苗助长 ba2 miao2 zhu4 zhang3  土生土长 tu3 sheng1 tu3 zhang3
+
+重复 chong2 fu4  重新 chong2 xin1  重庆 chong2 qing4  重叠 chong2 die2
+重建 chong2 jian4  重申 chong2 shen1  重阳 chong2 yang2  重逢 chong2 feng2
+重温 chong2 wen1  重播 chong2 bo1  重组 chong2 zu3  重写 chong2 xie3
+重来 chong2 lai2  重做 chong2 zuo4  重返 chong2 fan3  重演 chong2 yan3
+重现 chong2 xian4  重审 chong2 shen3  重修 chong2 xiu1  重印 chong2 yin4
+重装 chong2 zhuang1  重振 chong2 zhen4  重围 chong2 wei2  重重 chong2 chong2
+重聚 chong2 ju4  重整 chong2 zheng3  重合 chong2 he2  重婚 chong2 hun1
+重名 chong2 ming2  重影 chong2 ying3  重生 chong2 sheng1  双重 shuang1 chong2
+多重 duo1 chong2  重启 chong2 qi3  重置 chong2 zhi4  重选 chong2 xuan3
+重版 chong2 ban3  重译 chong2 yi4  重唱 chong2 chang4  九重 jiu3 chong2
+重峦叠嶂 chong2 luan2 die2 zhang4  重蹈覆辙 chong2 dao3 fu4 zhe2
+重整旗鼓 chong2 zheng3 qi2 gu3  重操旧业 chong2 cao1 jiu4 ye4
+重见天日 chong2 jian4 tian1 ri4  困难重重 kun4 nan chong2 chong2
+顾虑重重 gu4 lü4 chong2 chong2  重游 chong2 you2  重犯 chong2 fan4
+重排 chong2 pai2  重铸 chong2 zhu4  重订 chong2 ding4
+
+还钱 huan2 qian2  还债 huan2 zhai4  归还 gui1 huan2  偿还 chang2 huan2
+还款 huan2 kuan3  还原 huan2 yuan2  还击 huan2 ji1  还手 huan2 shou3
+还价 huan2 jia4  退还 tui4 huan2  奉还 feng4 huan2  返还 fan3 huan2
+交还 jiao1 huan2  送还 song4 huan2  还给 huan2 gei3  生还 sheng1 huan2
+还乡 huan2 xiang1  讨价还价 tao3 jia4 huan2 jia4  还嘴 huan2 zui3
+还礼 huan2 li3  还账 huan2 zhang4  发还 fa1 huan2  还贷 huan2 dai4
+还本 huan2 ben3  还魂 huan2 hun2  还俗 huan2 su2  还清 huan2 qing1
+以牙还牙 yi3 ya2 huan2 ya2  衣锦还乡 yi1 jin3 huan2 xiang1
+
+了解 liao3 jie3  了结 liao3 jie2  了不起 liao3 bu qi3  了却 liao3 que4
+了然 liao3 ran2  受不了 shou4 bu liao3  不得了 bu4 de2 liao3
+了如指掌 liao3 ru2 zhi3 zhang3  一了百了 yi1 liao3 bai3 liao3
+明了 ming2 liao3  了事 liao3 shi4  了断 liao3 duan4  未了 wei4 liao3
+了无 liao3 wu2  末了 mo4 liao3  不了了之 bu4 liao3 liao3 zhi1
+了得 liao3 de2  免不了 mian3 bu liao3  少不了 shao3 bu liao3
+忘不了 wang4 bu liao3  大不了 da4 bu liao3  一目了然 yi1 mu4 liao3 ran2
+直截了当 zhi2 jie2 liao3 dang4  了若指掌 liao3 ruo4 zhi3 zhang3
+
+觉得 jue2 de  记得 ji4 de  晓得 xiao3 de  值得 zhi2 de  懂得 dong3 de
+免得 mian3 de  使得 shi3 de  显得 xian3 de  舍得 she3 de  省得 sheng3 de
+见得 jian4 de  怪不得 guai4 bu de  巴不得 ba1 bu de  恨不得 hen4 bu de
+认得 ren4 de  落得 luo4 de  博得 bo2 de2  由得 you2 de
+长得 zhang3 de  变得 bian4 de  弄得 nong4 de  搞得 gao3 de  闹得 nao4 de
+说得 shuo1 de  做得 zuo4 de  过得 guo4 de  来得 lai2 de  跑得 pao3 de  写得 xie3 de
+
+目的 mu4 di4  的确 di2 que4  的士 di1 shi4  有的放矢 you3 di4 fang4 shi3
+众矢之的 zhong4 shi3 zhi1 di4  标的 biao1 di4  的的确确 di2 di2 que4 que4
+目的地 mu4 di4 di4
+
+着急 zhao2 ji2  着火 zhao2 huo3  着凉 zhao2 liang2  着迷 zhao2 mi2
+睡着 shui4 zhao2  着魔 zhao2 mo2  着慌 zhao2 huang1  用不着 yong4 bu zhao2
+犯不着 fan4 bu zhao2  找着 zhao3 zhao2  着陆 zhuo2 lu4  着手 zhuo2 shou3
+着想 zhuo2 xiang3  着重 zhuo2 zhong4  着眼 zhuo2 yan3  着装 zhuo2 zhuang1
+衣着 yi1 zhuo2  着落 zhuo2 luo4  执着 zhi2 zhuo2  沉着 chen2 zhuo2
+着实 zhuo2 shi2  着力 zhuo2 li4  穿着 chuan1 zhuo2  着色 zhuo2 se4
+附着 fu4 zhuo2  着墨 zhuo2 mo4  着笔 zhuo2 bi3  着眼点 zhuo2 yan3 dian3
+着数 zhao1 shu4  不着边际 bu4 zhuo2 bian1 ji4
+
+首都 shou3 du1  都市 du1 shi4  都城 du1 cheng2  成都 cheng2 du1
+京都 jing1 du1  古都 gu3 du1  都会 du1 hui4  建都 jian4 du1
+迁都 qian1 du1  定都 ding4 du1  国都 guo2 du1  都督 du1 du1
+都江堰 du1 jiang1 yan4  故都 gu4 du1  帝都 di4 du1  旧都 jiu4 du1
+
+和面 huo2 mian4  暖和 nuan3 huo  掺和 chan1 huo  搀和 chan1 huo
+附和 fu4 he4  应和 ying4 he4  唱和 chang4 he4  曲高和寡 qu3 gao1 he4 gua3
+和牌 hu2 pai2  热和 re4 huo  软和 ruan3 huo
+
+音乐 yin1 yue4  乐器 yue4 qi4  乐队 yue4 dui4  乐曲 yue4 qu3
+乐团 yue4 tuan2  乐章 yue4 zhang1  乐手 yue4 shou3  乐谱 yue4 pu3
+声乐 sheng1 yue4  器乐 qi4 yue4  民乐 min2 yue4  乐坛 yue4 tan2
+乐府 yue4 fu3  礼乐 li3 yue4  乐理 yue4 li3  乐师 yue4 shi1
+奏乐 zou4 yue4  配乐 pei4 yue4  乐律 yue4 lü4  管弦乐 guan3 xian2 yue4
+交响乐 jiao1 xiang3 yue4  打击乐 da3 ji1 yue4  音乐会 yin1 yue4 hui4
+音乐家 yin1 yue4 jia1  乐迷 yue4 mi2  爵士乐 jue2 shi4 yue4  摇滚乐 yao2 gun3 yue4
+
+睡觉 shui4 jiao4  午觉 wu3 jiao4  睡午觉 shui4 wu3 jiao4  一觉 yi1 jiao4
+睡大觉 shui4 da4 jiao4
+
+数一数二 shu3 yi1 shu3 er4  数落 shu3 luo  数数 shu3 shu4  数不清 shu3 bu4 qing1
+数不胜数 shu3 bu4 sheng4 shu3  屈指可数 qu1 zhi3 ke3 shu3  数见不鲜 shuo4 jian4 bu4 xian1
+数说 shu3 shuo1
+
+为了 wei4 le  因为 yin1 wei4  为什么 wei4 shen2 me  为何 wei4 he2
+为此 wei4 ci3  为人民服务 wei4 ren2 min2 fu2 wu4  为啥 wei4 sha2
+为着 wei4 zhe  为止 wei2 zhi3  为国捐躯 wei4 guo2 juan1 qu1
+舍己为人 she3 ji3 wei4 ren2  为民请命 wei4 min2 qing3 ming4
+
+便宜 pian2 yi  大腹便便 da4 fu4 pian2 pian2  便便 pian2 pian2
+
+调整 tiao2 zheng3  调节 tiao2 jie2  调解 tiao2 jie3  调和 tiao2 he2
+调皮 tiao2 pi2  调剂 tiao2 ji4  调控 tiao2 kong4  调理 tiao2 li3
+调料 tiao2 liao4  调味 tiao2 wei4  调戏 tiao2 xi4  调侃 tiao2 kan3
+调停 tiao2 ting2  调养 tiao2 yang3  调适 tiao2 shi4  空调 kong1 tiao2
+协调 xie2 tiao2  失调 shi1 tiao2  调教 tiao2 jiao4  微调 wei1 tiao2
+调频 tiao2 pin2  调制 tiao2 zhi4  调味品 tiao2 wei4 pin3  调解员 tiao2 jie3 yuan2
+风调雨顺 feng1 tiao2 yu3 shun4  调情 tiao2 qing2  调唆 tiao2 suo1
+调羹 tiao2 geng1  调色 tiao2 se4  调试 tiao2 shi4  调音 tiao2 yin1
+调幅 tiao2 fu2  调价 tiao2 jia4  调准 tiao2 zhun3  调匀 tiao2 yun2
+调校 tiao2 jiao4  调经 tiao2 jing1  调酒 tiao2 jiu3  调酒师 tiao2 jiu3 shi1
+
+传记 zhuan4 ji4  自传 zi4 zhuan4  传略 zhuan4 lüe4  列传 lie4 zhuan4
+外传 wai4 zhuan4  水浒传 shui3 hu3 zhuan4  经传 jing1 zhuan4
+小传 xiao3 zhuan4  评传 ping2 zhuan4  别传 bie2 zhuan4  名不见经传 ming2 bu2 jian4 jing1 zhuan4
+左传 zuo3 zhuan4
+
+西藏 xi1 zang4  藏族 zang4 zu2  藏文 zang4 wen2  藏语 zang4 yu3
+宝藏 bao3 zang4  藏青 zang4 qing1  藏獒 zang4 ao2  藏传佛教 zang4 chuan2 fo2 jiao4
+藏经 zang4 jing1  大藏经 da4 zang4 jing1  藏历 zang4 li4  藏区 zang4 qu1
+藏药 zang4 yao4  青藏 qing1 zang4  藏红花 zang4 hong2 hua1  藏人 zang4 ren2
+道藏 dao4 zang4  三藏 san1 zang4  宝藏库 bao3 zang4 ku4
+
+朝气 zhao1 qi4  朝夕 zhao1 xi1  朝三暮四 zhao1 san1 mu4 si4  今朝 jin1 zhao1
+朝霞 zhao1 xia2  朝露 zhao1 lu4  朝朝暮暮 zhao1 zhao1 mu4 mu4  一朝 yi1 zhao1
+朝气蓬勃 zhao1 qi4 peng2 bo2  朝不保夕 zhao1 bu4 bao3 xi1  朝发夕至 zhao1 fa1 xi1 zhi4
+朝令夕改 zhao1 ling4 xi1 gai3  朝思暮想 zhao1 si1 mu4 xiang3  朝阳 zhao1 yang2
+朝阳区 chao2 yang2 qu1  朝晖 zhao1 hui1
+
+差别 cha1 bie2  差异 cha1 yi4  差距 cha1 ju4  偏差 pian1 cha1  误差 wu4 cha1
+时差 shi2 cha1  差价 cha1 jia4  差额 cha1 e2  落差 luo4 cha1  温差 wen1 cha1
+逆差 ni4 cha1  顺差 shun4 cha1  差错 cha1 cuo4  差异性 cha1 yi4 xing4
+出差 chu1 chai1  差事 chai1 shi4  差遣 chai1 qian3  差使 chai1 shi3
+公差 gong1 chai1  信差 xin4 chai1  邮差 you2 chai1  交差 jiao1 chai1
+当差 dang1 chai1  钦差 qin1 chai1  参差 cen1 ci1  参差不齐 cen1 ci1 bu4 qi2
+阴差阳错 yin1 cha1 yang2 cuo4  差强人意 cha1 qiang2 ren2 yi4  标准差 biao1 zhun3 cha1
+方差 fang1 cha1  等差 deng3 cha1  差分 cha1 fen1  视差 shi4 cha1
+
+率领 shuai4 ling3  率先 shuai4 xian1  坦率 tan3 shuai4  草率 cao3 shuai4
+直率 zhi2 shuai4  轻率 qing1 shuai4  表率 biao3 shuai4  统率 tong3 shuai4
+率直 shuai4 zhi2  率真 shuai4 zhen1  率性 shuai4 xing4  真率 zhen1 shuai4
+
+处理 chu3 li3  处分 chu3 fen4  处罚 chu3 fa2  处境 chu3 jing4  处置 chu3 zhi4
+相处 xiang1 chu3  处于 chu3 yu2  处事 chu3 shi4  处世 chu3 shi4
+处方 chu3 fang1  处女 chu3 nü3  处理器 chu3 li3 qi4  处决 chu3 jue2
+处在 chu3 zai4  独处 du2 chu3  判处 pan4 chu3  惩处 cheng2 chu3
+论处 lun4 chu3  处以 chu3 yi3  处理厂 chu3 li3 chang3  和平共处 he2 ping2 gong4 chu3
+处心积虑 chu3 xin1 ji1 lü4  设身处地 she4 shen1 chu3 di4  处之泰然 chu3 zhi1 tai4 ran2
+处变不惊 chu3 bian4 bu4 jing1  处女作 chu3 nü3 zuo4  处女座 chu3 nü3 zuo4
+查处 cha2 chu3  处治 chu3 zhi4  共处 gong4 chu3  处境艰难 chu3 jing4 jian1 nan2
+
+冲劲 chong4 jin4  冲着 chong4 zhe  冲压 chong4 ya1  冲床 chong4 chuang2
+
+恰当 qia4 dang4  适当 shi4 dang4  妥当 tuo3 dang4  上当 shang4 dang4
+当铺 dang4 pu4  当作 dang4 zuo4  当成 dang4 cheng2  当真 dang4 zhen1
+得当 de2 dang4  停当 ting2 dang4  稳当 wen3 dang4  典当 dian3 dang4
+勾当 gou4 dang4  当做 dang4 zuo4  正当 zheng4 dang1  失当 shi1 dang4
+不当 bu4 dang4  当天 dang1 tian1  当回事 dang4 hui2 shi4
+安步当车 an1 bu4 dang4 che1  以一当十 yi3 yi1 dang1 shi2  妥妥当当 tuo3 tuo3 dang4 dang4
+
+头发 tou2 fa4  理发 li3 fa4  发型 fa4 xing2  发廊 fa4 lang2  白发 bai2 fa4
+毛发 mao2 fa4  发丝 fa4 si1  理发店 li3 fa4 dian4  削发 xue1 fa4
+怒发冲冠 nu4 fa4 chong1 guan1  发卡 fa4 qia3  假发 jia3 fa4  黑发 hei1 fa4
+金发 jin1 fa4  短发 duan3 fa4  长发 chang2 fa4  秀发 xiu4 fa4  脱发 tuo1 fa4
+染发 ran3 fa4  烫发 tang4 fa4  鹤发童颜 he4 fa4 tong2 yan2  千钧一发 qian1 jun1 yi1 fa4
+令人发指 ling4 ren2 fa4 zhi3  发夹 fa4 jia1  生发 sheng1 fa4  护发素 hu4 fa4 su4
+
+干净 gan1 jing4  干燥 gan1 zao4  干旱 gan1 han4  干扰 gan1 rao3
+干预 gan1 yu4  干涉 gan1 she4  干杯 gan1 bei1  若干 ruo4 gan1
+饼干 bing3 gan1  干货 gan1 huo4  干果 gan1 guo3  干脆 gan1 cui4
+干枯 gan1 ku1  干涸 gan1 he2  干柴 gan1 chai2  干粮 gan1 liang
+干瘪 gan1 bie3  干爹 gan1 die1  干妈 gan1 ma1  干燥剂 gan1 zao4 ji4
+干洗 gan1 xi3  干涩 gan1 se4  干戈 gan1 ge1  相干 xiang1 gan1
+不相干 bu4 xiang1 gan1  天干 tian1 gan1  干电池 gan1 dian4 chi2
+干冰 gan1 bing1  干巴巴 gan1 ba1 ba1  晒干 shai4 gan1  烘干 hong1 gan1
+擦干 ca1 gan1  葡萄干 pu2 tao gan1  豆腐干 dou4 fu gan1  肉干 rou4 gan1
+干红 gan1 hong2  干咳 gan1 ke2  干瞪眼 gan1 deng4 yan3  干笑 gan1 xiao4
+干着急 gan1 zhao2 ji2  干等 gan1 deng3  干儿子 gan1 er2 zi  干女儿 gan1 nü3 er2
+干净利落 gan1 jing4 li4 luo  干干净净 gan1 gan1 jing4 jing4  风干 feng1 gan1
+干扰素 gan1 rao3 su4  口干舌燥 kou3 gan1 she2 zao4  外强中干 wai4 qiang2 zhong1 gan1
+干支 gan1 zhi1  干系 gan1 xi4  干瘦 gan1 shou4  干旱区 gan1 han4 qu1
+
+爱好 ai4 hao4  好奇 hao4 qi2  好客 hao4 ke4  好学 hao4 xue2  好胜 hao4 sheng4
+好战 hao4 zhan4  嗜好 shi4 hao4  喜好 xi3 hao4  偏好 pian1 hao4
+癖好 pi3 hao4  好色 hao4 se4  好逸恶劳 hao4 yi4 wu4 lao2  投其所好 tou2 qi2 suo3 hao4
+好高骛远 hao4 gao1 wu4 yuan3  好大喜功 hao4 da4 xi3 gong1  好奇心 hao4 qi2 xin1
+爱好者 ai4 hao4 zhe3  好动 hao4 dong4  好强 hao4 qiang2  好事者 hao4 shi4 zhe3
+洁身自好 jie2 shen1 zi4 hao4  好吃懒做 hao4 chi1 lan3 zuo4  好恶 hao4 wu4
+
+看守 kan1 shou3  看护 kan1 hu4  看管 kan1 guan3  看家 kan1 jia1
+看门 kan1 men2  看门人 kan1 men2 ren2  看守所 kan1 shou3 suo3
+
+空白 kong4 bai2  空闲 kong4 xian2  空隙 kong4 xi4  空地 kong4 di4
+有空 you3 kong4  抽空 chou1 kong4  空缺 kong4 que1  填空 tian2 kong4
+空当 kong4 dang1  空暇 kong4 xia2  空额 kong4 e2  没空 mei2 kong4
+空子 kong4 zi  钻空子 zuan1 kong4 zi  空白点 kong4 bai2 dian3
+
+高兴 gao1 xing4  兴趣 xing4 qu4  兴致 xing4 zhi4  兴高采烈 xing4 gao1 cai3 lie4
+即兴 ji2 xing4  扫兴 sao3 xing4  助兴 zhu4 xing4  尽兴 jin4 xing4
+兴味 xing4 wei4  雅兴 ya3 xing4  游兴 you2 xing4  败兴 bai4 xing4
+兴头 xing4 tou  兴冲冲 xing4 chong1 chong1  兴致勃勃 xing4 zhi4 bo2 bo2
+兴趣盎然 xing4 qu4 ang4 ran2  乘兴 cheng2 xing4  诗兴 shi1 xing4
+
+种植 zhong4 zhi2  种地 zhong4 di4  种田 zhong4 tian2  耕种 geng1 zhong4
+种树 zhong4 shu4  种花 zhong4 hua1  种菜 zhong4 cai4  接种 jie1 zhong4
+种庄稼 zhong4 zhuang1 jia  种瓜得瓜 zhong4 gua1 de2 gua1  种豆得豆 zhong4 dou4 de2 dou4
+栽种 zai1 zhong4  种植业 zhong4 zhi2 ye4  种植园 zhong4 zhi2 yuan2
+播种 bo1 zhong4  复种 fu4 zhong4  抢种 qiang3 zhong4  种痘 zhong4 dou4
+
+一只 yi1 zhi1  船只 chuan2 zhi1  只身 zhi1 shen1  形单影只 xing2 dan1 ying3 zhi1
+两只 liang3 zhi1  几只 ji3 zhi1  只言片语 zhi1 yan2 pian4 yu3  这只 zhe4 zhi1
+那只 na4 zhi1  每只 mei3 zhi1  只字不提 zhi1 zi4 bu4 ti2  独具只眼 du2 ju4 zhi1 yan3
+
+中奖 zhong4 jiang3  中毒 zhong4 du2  中弹 zhong4 dan4  中暑 zhong4 shu3
+中标 zhong4 biao1  中意 zhong4 yi4  中选 zhong4 xuan3  打中 da3 zhong4
+击中 ji1 zhong4  命中 ming4 zhong4  猜中 cai1 zhong4  看中 kan4 zhong4
+相中 xiang1 zhong4  正中下怀 zheng4 zhong4 xia4 huai2  切中 qie4 zhong4
+考中 kao3 zhong4  射中 she4 zhong4  中风 zhong4 feng1  百发百中 bai3 fa1 bai3 zhong4
+命中率 ming4 zhong4 lü4  一语中的 yi1 yu3 zhong4 di4  中彩 zhong4 cai3
+中计 zhong4 ji4  中邪 zhong4 xie2  中招 zhong4 zhao1  食物中毒 shi2 wu4 zhong4 du2
+
+正月 zheng1 yue4  正旦 zheng1 dan4
+
+相声 xiang4 sheng  照相 zhao4 xiang4  相机 xiang4 ji1  照相机 zhao4 xiang4 ji1
+首相 shou3 xiang4  宰相 zai3 xiang4  真相 zhen1 xiang4  相貌 xiang4 mao4
+相片 xiang4 pian4  面相 mian4 xiang4  亮相 liang4 xiang4  丞相 cheng2 xiang4
+相册 xiang4 ce4  属相 shu3 xiang4  变相 bian4 xiang4  卖相 mai4 xiang4
+品相 pin3 xiang4  扮相 ban4 xiang4  看相 kan4 xiang4  相士 xiang4 shi4
+吉人天相 ji2 ren2 tian1 xiang4  真相大白 zhen1 xiang4 da4 bai2  相术 xiang4 shu4
+将相 jiang4 xiang4  相国 xiang4 guo2  福相 fu2 xiang4  可怜相 ke3 lian2 xiang4
+色相 se4 xiang4  皮相 pi2 xiang4  手相 shou3 xiang4  相位 xiang4 wei4
+数码相机 shu4 ma3 xiang4 ji1  单反相机 dan1 fan3 xiang4 ji1  相声演员 xiang4 sheng yan3 yuan2
+
+反应 fan3 ying4  答应 da1 ying  应用 ying4 yong4  应对 ying4 dui4
+响应 xiang3 ying4  适应 shi4 ying4  供应 gong1 ying4  对应 dui4 ying4
+效应 xiao4 ying4  应付 ying4 fu  应急 ying4 ji2  应邀 ying4 yao1
+应聘 ying4 pin4  应变 ying4 bian4  应酬 ying4 chou  相应 xiang1 ying4
+感应 gan3 ying4  应战 ying4 zhan4  顺应 shun4 ying4  呼应 hu1 ying4
+内应 nei4 ying4  应验 ying4 yan4  照应 zhao4 ying4  报应 bao4 ying4
+应征 ying4 zheng1  应答 ying4 da2  应允 ying4 yun3  应声 ying4 sheng1
+应景 ying4 jing3  应考 ying4 kao3  应试 ying4 shi4  应届 ying4 jie4
+应运而生 ying4 yun4 er2 sheng1  应接不暇 ying4 jie1 bu4 xia2  应用程序 ying4 yong4 cheng2 xu4
+反应堆 fan3 ying4 dui1  供应商 gong1 ying4 shang1  应用软件 ying4 yong4 ruan3 jian4
+得心应手 de2 xin1 ying4 shou3  随机应变 sui2 ji1 ying4 bian4  有求必应 you3 qiu2 bi4 ying4
+应届生 ying4 jie4 sheng1  应用于 ying4 yong4 yu2  供应链 gong1 ying4 lian4
+应诺 ying4 nuo4  应急管理 ying4 ji2 guan3 li3  化学反应 hua4 xue2 fan3 ying4
+
+角色 jue2 se4  主角 zhu3 jue2  配角 pei4 jue2  角逐 jue2 zhu2  口角 kou3 jue2
+名角 ming2 jue2  丑角 chou3 jue2  旦角 dan4 jue2  女主角 nü3 zhu3 jue2
+男主角 nan2 zhu3 jue2  角色扮演 jue2 se4 ban4 yan3  生角 sheng1 jue2
+
+薄弱 bo2 ruo4  单薄 dan1 bo2  淡薄 dan4 bo2  刻薄 ke4 bo2  浅薄 qian3 bo2
+微薄 wei1 bo2  薄利 bo2 li4  厚此薄彼 hou4 ci3 bo2 bi3  轻薄 qing1 bo2
+薄膜 bo2 mo2  稀薄 xi1 bo2  菲薄 fei3 bo2  日薄西山 ri4 bo2 xi1 shan1
+薄雾 bo2 wu4  薄荷 bo4 he  薄利多销 bo2 li4 duo1 xiao1  妄自菲薄 wang4 zi4 fei3 bo2
+如履薄冰 ru2 lü3 bo2 bing1  红颜薄命 hong2 yan2 bo2 ming4  鄙薄 bi3 bo2
+
+血淋淋 xie3 lin2 lin2  血晕 xie3 yun1
+
+尽管 jin3 guan3  尽快 jin3 kuai4  尽量 jin3 liang4  尽早 jin3 zao3
+尽可能 jin3 ke3 neng2  尽先 jin3 xian1  尽着 jin3 zhe  尽管如此 jin3 guan3 ru2 ci3
+
+少年 shao4 nian2  少女 shao4 nü3  少儿 shao4 er2  少妇 shao4 fu4
+少将 shao4 jiang4  少校 shao4 xiao4  少尉 shao4 wei4  少爷 shao4 ye
+少林 shao4 lin2  少奶奶 shao4 nai3 nai  少先队 shao4 xian1 dui4
+青少年 qing1 shao4 nian2  老少 lao3 shao4  阔少 kuo4 shao4  少林寺 shao4 lin2 si4
+少年宫 shao4 nian2 gong1  男女老少 nan2 nü3 lao3 shao4  少东家 shao4 dong1 jia
+少不更事 shao4 bu4 geng1 shi4  少帅 shao4 shuai4  少主 shao4 zhu3
+
+大夫 dai4 fu  大王 dai4 wang  山大王 shan1 dai4 wang
+
+模样 mu2 yang4  模具 mu2 ju4  模板 mu2 ban3  模子 mu2 zi  一模一样 yi1 mu2 yi1 yang4
+装模作样 zhuang1 mu2 zuo4 yang4  人模狗样 ren2 mu2 gou3 yang4
+
+系鞋带 ji4 xie2 dai4  系上 ji4 shang4  系好 ji4 hao3  系紧 ji4 jin3
+
+更新 geng1 xin1  更改 geng1 gai3  更换 geng1 huan4  变更 bian4 geng1
+更正 geng1 zheng4  更替 geng1 ti4  更迭 geng1 die2  更衣 geng1 yi1
+更名 geng1 ming2  更生 geng1 sheng1  三更 san1 geng1  打更 da3 geng1
+更年期 geng1 nian2 qi1  自力更生 zi4 li4 geng1 sheng1  更衣室 geng1 yi1 shi4
+更新换代 geng1 xin1 huan4 dai4  万象更新 wan4 xiang4 geng1 xin1  少不更事 shao4 bu4 geng1 shi4
+更动 geng1 dong4  五更 wu3 geng1  半夜三更 ban4 ye4 san1 geng1  更夫 geng1 fu1
+除旧更新 chu2 jiu4 geng1 xin1
+
+成分 cheng2 fen4  过分 guo4 fen4  本分 ben3 fen4  水分 shui3 fen4
+养分 yang3 fen4  分外 fen4 wai4  分量 fen4 liang4  名分 ming2 fen4
+缘分 yuan2 fen4  天分 tian1 fen4  福分 fu2 fen4  情分 qing2 fen4
+安分 an1 fen4  辈分 bei4 fen4  身分 shen1 fen4  非分 fei1 fen4
+恰如其分 qia4 ru2 qi2 fen4  安分守己 an1 fen4 shou3 ji3  盐分 yan2 fen4
+糖分 tang2 fen4  过分了 guo4 fen4 le  分内 fen4 nei4  部分 bu4 fen
+
+教书 jiao1 shu1  教给 jiao1 gei3  教书育人 jiao1 shu1 yu4 ren2
+
+浑身解数 hun2 shen1 xie4 shu4  押解 ya1 jie4  解元 jie4 yuan2  起解 qi3 jie4
+解送 jie4 song4  解数 xie4 shu4
+
+反省 fan3 xing3  省亲 xing3 qin1  省悟 xing3 wu4  不省人事 bu4 xing3 ren2 shi4
+内省 nei4 xing3  自省 zi4 xing3  发人深省 fa1 ren2 shen1 xing3  省视 xing3 shi4
+吾日三省吾身 wu2 ri4 san1 xing3 wu2 shen1
+
+似的 shi4 de
+
+测量 ce4 liang2  丈量 zhang4 liang2  打量 da3 liang  思量 si1 liang
+商量 shang1 liang  量具 liang2 ju4  估量 gu1 liang  衡量 heng2 liang2
+量体温 liang2 ti3 wen1  量体裁衣 liang4 ti3 cai2 yi1  掂量 dian1 liang
+量杯 liang2 bei1  量筒 liang2 tong3  测量仪 ce4 liang2 yi2  测量员 ce4 liang2 yuan2
+酌量 zhuo2 liang  忖量 cun3 liang  度量衡 du4 liang4 heng2  不可估量 bu4 ke3 gu1 liang
+
+露面 lou4 mian4  露脸 lou4 lian3  露馅 lou4 xian4  露馅儿 lou4 xian4 er
+露一手 lou4 yi1 shou3  露底 lou4 di3  抛头露面 pao1 tou2 lou4 mian4  露马脚 lou4 ma3 jiao3
+露富 lou4 fu4  露怯 lou4 qie4  露相 lou4 xiang4
+
+丢三落四 diu1 san1 la4 si4  落枕 lao4 zhen3  落色 lao4 shai3  落价 lao4 jia4
+落下 luo4 xia4  落埋怨 lao4 man2 yuan4  大大落落 da4 da4 luo4 luo4
+
+对称 dui4 chen4  相称 xiang1 chen4  匀称 yun2 chen4  称心 chen4 xin1
+称职 chen4 zhi2  称心如意 chen4 xin1 ru2 yi4  不称职 bu4 chen4 zhi2
+对称性 dui4 chen4 xing4
+
+几乎 ji1 hu1  茶几 cha2 ji1  几率 ji1 lü4  几近 ji1 jin4  几乎是 ji1 hu1 shi4
+窗明几净 chuang1 ming2 ji1 jing4
+
+假期 jia4 qi1  放假 fang4 jia4  请假 qing3 jia4  暑假 shu3 jia4  寒假 han2 jia4
+休假 xiu1 jia4  度假 du4 jia4  假日 jia4 ri4  病假 bing4 jia4  婚假 hun1 jia4
+年假 nian2 jia4  产假 chan3 jia4  假条 jia4 tiao2  销假 xiao1 jia4
+事假 shi4 jia4  长假 chang2 jia4  度假村 du4 jia4 cun1  节假日 jie2 jia4 ri4
+假日经济 jia4 ri4 jing1 ji4  休假日 xiu1 jia4 ri4  请病假 qing3 bing4 jia4
+暑假期间 shu3 jia4 qi1 jian1  假期间 jia4 qi1 jian1  例假 li4 jia4
+
+投降 tou2 xiang2  降服 xiang2 fu2  归降 gui1 xiang2  降龙伏虎 xiang2 long2 fu2 hu3
+受降 shou4 xiang2  劝降 quan4 xiang2  诈降 zha4 xiang2  招降 zhao1 xiang2
+一物降一物 yi1 wu4 xiang2 yi1 wu4
+
+大将 da4 jiang4  上将 shang4 jiang4  中将 zhong1 jiang4  主将 zhu3 jiang4
+名将 ming2 jiang4  将领 jiang4 ling3  将士 jiang4 shi4  将帅 jiang4 shuai4
+武将 wu3 jiang4  猛将 meng3 jiang4  干将 gan4 jiang4  将官 jiang4 guan1
+强将 qiang2 jiang4  败将 bai4 jiang4  老将 lao3 jiang4  兵来将挡 bing1 lai2 jiang4 dang3
+良将 liang2 jiang4  宿将 su4 jiang4  战将 zhan4 jiang4  健将 jian4 jiang4
+爱将 ai4 jiang4  虾兵蟹将 xia1 bing1 xie4 jiang4  损兵折将 sun3 bing1 zhe2 jiang4
+过五关斩六将 guo4 wu3 guan1 zhan3 liu4 jiang4  将门 jiang4 men2  麻将 ma2 jiang4
+将校 jiang4 xiao4  将相 jiang4 xiang4  运动健将 yun4 dong4 jian4 jiang4
+
+结实 jie1 shi  结巴 jie1 ba  开花结果 kai1 hua1 jie1 guo3
+
+供给 gong1 ji3  给予 ji3 yu3  自给自足 zi4 ji3 zi4 zu2  补给 bu3 ji3
+配给 pei4 ji3  给养 ji3 yang3  自给 zi4 ji3  给予者 ji3 yu3 zhe3
+家给人足 jia1 ji3 ren2 zu2  目不暇给 mu4 bu4 xia2 ji3
+
+勉强 mian3 qiang3  强迫 qiang3 po4  强求 qiang3 qiu2  强词夺理 qiang3 ci2 duo2 li3
+强人所难 qiang3 ren2 suo3 nan2  牵强 qian1 qiang3  强辩 qiang3 bian4
+倔强 jue2 jiang4  强嘴 jiang4 zui3  牵强附会 qian1 qiang3 fu4 hui4
+强颜欢笑 qiang3 yan2 huan1 xiao4  强迫症 qiang3 po4 zheng4  勉勉强强 mian3 mian3 qiang3 qiang3
+强制 qiang2 zhi4  强逼 qiang3 bi1
+
+弯曲 wan1 qu1  曲折 qu1 zhe2  歪曲 wai1 qu1  曲线 qu1 xian4  曲解 qu1 jie3
+委曲 wei3 qu1  曲直 qu1 zhi2  扭曲 niu3 qu1  曲面 qu1 mian4  曲率 qu1 lü4
+曲径 qu1 jing4  曲棍球 qu1 gun4 qiu2  曲别针 qu1 bie2 zhen1  委曲求全 wei3 qu1 qiu2 quan2
+曲线图 qu1 xian4 tu2  弯弯曲曲 wan1 wan1 qu1 qu1  曲阜 qu1 fu4  曲意逢迎 qu1 yi4 feng2 ying2
+曲奇 qu1 qi2  是非曲直 shi4 fei1 qu1 zhi2  曲里拐弯 qu1 li guai3 wan1  酒曲 jiu3 qu1
+
+灾难 zai1 nan4  难民 nan4 min2  苦难 ku3 nan4  遇难 yu4 nan4  难友 nan4 you3
+患难 huan4 nan4  避难 bi4 nan4  受难 shou4 nan4  劫难 jie2 nan4
+遇难者 yu4 nan4 zhe3  逃难 tao2 nan4  发难 fa1 nan4  责难 ze2 nan4
+非难 fei1 nan4  刁难 diao1 nan4  罹难 li2 nan4  国难 guo2 nan4
+殉难 xun4 nan4  空难 kong1 nan4  海难 hai3 nan4  矿难 kuang4 nan4
+大难 da4 nan4  落难 luo4 nan4  难兄难弟 nan4 xiong1 nan4 di4  遭难 zao1 nan4
+患难之交 huan4 nan4 zhi1 jiao1  患难与共 huan4 nan4 yu3 gong4  难民营 nan4 min2 ying2
+避难所 bi4 nan4 suo3  多灾多难 duo1 zai1 duo1 nan4  灾难性 zai1 nan4 xing4
+
+星宿 xing1 xiu4  二十八宿 er4 shi2 ba1 xiu4  一宿 yi1 xiu3
+
+人参 ren2 shen1  海参 hai3 shen1  党参 dang3 shen1  高丽参 gao1 li4 shen1
+西洋参 xi1 yang2 shen1  参商 shen1 shang1
+
+挣扎 zheng1 zha2  扎挣 zha2 zheng  扎辫子 za1 bian4 zi
+
+子弹 zi3 dan4  炸弹 zha4 dan4  导弹 dao3 dan4  弹药 dan4 yao4  炮弹 pao4 dan4
+弹头 dan4 tou2  核弹 he2 dan4  手榴弹 shou3 liu2 dan4  弹弓 dan4 gong1
+弹丸 dan4 wan2  枪弹 qiang1 dan4  原子弹 yuan2 zi3 dan4  氢弹 qing1 dan4
+弹道 dan4 dao4  飞弹 fei1 dan4  榴弹 liu2 dan4  弹片 dan4 pian4
+弹壳 dan4 ke2  弹夹 dan4 jia1  弹匣 dan4 xia2  流弹 liu2 dan4
+穿甲弹 chuan1 jia3 dan4  燃烧弹 ran2 shao1 dan4  催泪弹 cui1 lei4 dan4
+烟雾弹 yan1 wu4 dan4  弹坑 dan4 keng1  弹孔 dan4 kong3  弹药库 dan4 yao4 ku4
+导弹防御 dao3 dan4 fang2 yu4  弹尽粮绝 dan4 jin4 liang2 jue2  枪林弹雨 qiang1 lin2 dan4 yu3
+定时炸弹 ding4 shi2 zha4 dan4  糖衣炮弹 tang2 yi1 pao4 dan4  炮弹壳 pao4 dan4 ke2
+弹着点 dan4 zhuo2 dian3  霰弹 xian4 dan4  哑弹 ya3 dan4  空包弹 kong1 bao1 dan4
+核弹头 he2 dan4 tou2  洲际导弹 zhou1 ji4 dao3 dan4  弹道导弹 dan4 dao4 dao3 dan4
+
+炮制 pao2 zhi4  如法炮制 ru2 fa3 pao2 zhi4  炮烙 pao2 luo4
+
+堵塞 du3 se4  阻塞 zu3 se4  闭塞 bi4 se4  搪塞 tang2 se4  塞责 se4 ze2
+敷衍塞责 fu1 yan3 se4 ze2  茅塞顿开 mao2 se4 dun4 kai1  要塞 yao4 sai4
+边塞 bian1 sai4  塞外 sai4 wai4  塞翁失马 sai4 weng1 shi1 ma3  关塞 guan1 sai4
+栓塞 shuan1 se4  梗塞 geng3 se4  心肌梗塞 xin1 ji1 geng3 se4  鼻塞 bi2 se4
+充塞 chong1 se4  淤塞 yu1 se4  塞北 sai4 bei3  出塞 chu1 sai4
+塞尔维亚 sai4 er3 wei2 ya4  塞浦路斯 sai4 pu3 lu4 si1  塞内加尔 sai4 nei4 jia1 er3
+
+散文 san3 wen2  散装 san3 zhuang1  松散 song1 san3  零散 ling2 san3
+散漫 san3 man4  懒散 lan3 san3  散光 san3 guang1  散架 san3 jia4
+闲散 xian2 san3  零零散散 ling2 ling2 san3 san3  散兵游勇 san3 bing1 you2 yong3
+散文诗 san3 wen2 shi1  散件 san3 jian4  散户 san3 hu4  散客 san3 ke4
+散座 san3 zuo4  散曲 san3 qu3  散剂 san3 ji4
+
+扇动 shan1 dong4  扇风 shan1 feng1  扇风点火 shan1 feng1 dian3 huo3
+
+挑战 tiao3 zhan4  挑衅 tiao3 xin4  挑拨 tiao3 bo1  挑逗 tiao3 dou4
+挑唆 tiao3 suo1  挑起 tiao3 qi3  挑明 tiao3 ming2  挑大梁 tiao3 da4 liang2
+挑拨离间 tiao3 bo1 li2 jian4  挑战者 tiao3 zhan4 zhe3  挑战性 tiao3 zhan4 xing4
+挑灯夜战 tiao3 deng1 ye4 zhan4  挑衅性 tiao3 xin4 xing4  挑头 tiao3 tou2
+
+畜牧 xu4 mu4  畜牧业 xu4 mu4 ye4  畜养 xu4 yang3  畜产 xu4 chan3
+畜产品 xu4 chan3 pin3  畜牧局 xu4 mu4 ju2
+
+厌恶 yan4 wu4  可恶 ke3 wu4  憎恶 zeng1 wu4  深恶痛绝 shen1 wu4 tong4 jue2
+恶心 e3 xin1  好逸恶劳 hao4 yi4 wu4 lao2  恶心人 e3 xin1 ren2
+
+供奉 gong4 feng4  供品 gong4 pin3  口供 kou3 gong4  供认 gong4 ren4
+供词 gong4 ci2  供述 gong4 shu4  招供 zhao1 gong4  上供 shang4 gong4
+供状 gong4 zhuang4  翻供 fan1 gong4  供桌 gong4 zhuo1  供认不讳 gong4 ren4 bu4 hui4
+逼供 bi1 gong4  串供 chuan4 gong4  供职 gong4 zhi2  供养 gong4 yang3
+
+淹没 yan1 mo4  没收 mo4 shou1  埋没 mai2 mo4  出没 chu1 mo4  沉没 chen2 mo4
+吞没 tun1 mo4  覆没 fu4 mo4  隐没 yin3 mo4  没落 mo4 luo4  辱没 ru3 mo4
+湮没 yan1 mo4  神出鬼没 shen2 chu1 gui3 mo4  全军覆没 quan2 jun1 fu4 mo4
+没齿难忘 mo4 chi3 nan2 wang4  没世 mo4 shi4  没入 mo4 ru4  鬼没 gui3 mo4
+没顶 mo4 ding3  湮没无闻 yan1 mo4 wu2 wen2
+
+晃眼 huang3 yan3  一晃 yi1 huang3  虚晃一枪 xu1 huang3 yi1 qiang1
+
+记载 ji4 zai3  登载 deng1 zai3  转载 zhuan3 zai3  刊载 kan1 zai3
+一年半载 yi1 nian2 ban4 zai3  千载难逢 qian1 zai3 nan2 feng2  千载 qian1 zai3
+三年五载 san1 nian2 wu3 zai3  连载 lian2 zai3  载入史册 zai3 ru4 shi3 ce4
+下载 xia4 zai4
+
+投奔 tou2 ben4  奔头 ben4 tou  奔着 ben4 zhe
+
+呢子 ni2 zi  毛呢 mao2 ni2  呢喃 ni2 nan2  呢绒 ni2 rong2  花呢 hua1 ni2
+吗啡 ma3 fei1  干吗 gan4 ma2
+
+关卡 guan1 qia3  卡子 qia3 zi  哨卡 shao4 qia3  卡脖子 qia3 bo2 zi  发卡 fa4 qia3
+卡壳 qia3 ke2  卡住 qia3 zhu4
+
+剥皮 bao1 pi2  剥花生 bao1 hua1 sheng1  剥开 bao1 kai1
+
+削皮 xiao1 pi2  削铅笔 xiao1 qian1 bi3  切削 qie1 xiao1  削尖 xiao1 jian1
+
+屏住 bing3 zhu4  屏息 bing3 xi1  屏弃 bing3 qi4  屏气 bing3 qi4  屏除 bing3 chu2
+屏住呼吸 bing3 zhu4 hu1 xi1  屏气凝神 bing3 qi4 ning2 shen2  屏退 bing3 tui4
+
+湖泊 hu2 po1  血泊 xue4 po1  梁山泊 liang2 shan1 po1
+
+倒车 dao4 che1  倒退 dao4 tui4  倒影 dao4 ying3  倒立 dao4 li4  倒数 dao4 shu3
+倒是 dao4 shi4  倒流 dao4 liu2  倒挂 dao4 gua4  倒水 dao4 shui3  倒茶 dao4 cha2
+倒入 dao4 ru4  倒置 dao4 zhi4  倒装 dao4 zhuang1  反倒 fan3 dao4
+倒计时 dao4 ji4 shi2  倒贴 dao4 tie1  倒叙 dao4 xu4  倒行逆施 dao4 xing2 ni4 shi1
+倒打一耙 dao4 da3 yi1 pa2  倒背如流 dao4 bei4 ru2 liu2  本末倒置 ben3 mo4 dao4 zhi4
+倒过来 dao4 guo4 lai2  倒挂金钩 dao4 gua4 jin1 gou1  倒映 dao4 ying4  倒转 dao4 zhuan3
+倒出 dao4 chu1  倒掉 dao4 diao4  倒退着 dao4 tui4 zhe
+
+旋风 xuan4 feng1  旋子 xuan4 zi
+
+转动 zhuan4 dong4  旋转 xuan2 zhuan4  转圈 zhuan4 quan1  转盘 zhuan4 pan2
+打转 da3 zhuan4  转速 zhuan4 su4  自转 zi4 zhuan4  公转 gong1 zhuan4
+团团转 tuan2 tuan2 zhuan4  转悠 zhuan4 you  转椅 zhuan4 yi3  转门 zhuan4 men2
+转轴 zhuan4 zhou2  旋转门 xuan2 zhuan4 men2  转来转去 zhuan4 lai2 zhuan4 qu4
+转圈圈 zhuan4 quan1 quan1  滴溜溜转 di1 liu1 liu1 zhuan4  转台 zhuan4 tai2
+
+创伤 chuang1 shang1  重创 zhong4 chuang1  创口 chuang1 kou3  创面 chuang1 mian4
+创可贴 chuang1 ke3 tie1  创痛 chuang1 tong4  刀创 dao1 chuang1
+
+石磨 shi2 mo4  磨坊 mo4 fang2  磨盘 mo4 pan2  推磨 tui1 mo4  磨面 mo4 mian4
+磨不开 mo4 bu kai1  磨房 mo4 fang2  拉磨 la1 mo4
+
+闷热 men1 re4  闷声 men1 sheng1  闷头 men1 tou2  闷声不响 men1 sheng1 bu4 xiang3
+闷葫芦 men4 hu2 lu  闷得慌 men4 de huang1  闷声闷气 men1 sheng1 men1 qi4
+
+蒙古 meng3 gu3  内蒙古 nei4 meng3 gu3  蒙古族 meng3 gu3 zu2  蒙古包 meng3 gu3 bao1
+蒙古国 meng3 gu3 guo2  蒙古语 meng3 gu3 yu3  蒙骗 meng1 pian4  瞎蒙 xia1 meng1
+蒙在鼓里 meng2 zai4 gu3 li3  内蒙 nei4 meng3  外蒙 wai4 meng3  蒙族 meng3 zu2
+
+曾祖 zeng1 zu3  曾孙 zeng1 sun1  曾祖父 zeng1 zu3 fu4  曾祖母 zeng1 zu3 mu3
+曾国藩 zeng1 guo2 fan1  曾孙女 zeng1 sun1 nü3  曾子 zeng1 zi3
+
+单于 chan2 yu2
+
+朴刀 po1 dao1  朴树 po4 shu4  朴正熙 piao2 zheng4 xi1  朴槿惠 piao2 jin3 hui4
+
+牛仔 niu2 zai3  牛仔裤 niu2 zai3 ku4  猪仔 zhu1 zai3  打工仔 da3 gong1 zai3
+肥仔 fei2 zai3  靓仔 liang4 zai3  公仔 gong1 zai3  仔仔 zai3 zai3
+外来仔 wai4 lai2 zai3  牛仔服 niu2 zai3 fu2  小仔 xiao3 zai3
+
+择菜 zhai2 cai4  择不开 zhai2 bu kai1  择席 zhai2 xi2
+
+色子 shai3 zi  掉色 diao4 shai3  退色 tui4 shai3  落色 lao4 shai3  上色 shang4 shai3
+色儿 shai3 er
+
+地壳 di4 qiao4  甲壳 jia3 qiao4  躯壳 qu1 qiao4  金蝉脱壳 jin1 chan2 tuo1 qiao4
+地壳运动 di4 qiao4 yun4 dong4  甲壳虫 jia3 qiao4 chong2  甲壳类 jia3 qiao4 lei4
+
+脉脉 mo4 mo4  含情脉脉 han2 qing2 mo4 mo4  脉脉含情 mo4 mo4 han2 qing2
+
+恐吓 kong3 he4  恫吓 dong4 he4  吓唬 xia4 hu  恐吓信 kong3 he4 xin4
+
+华山 hua4 shan1  华佗 hua4 tuo2
+
+盛饭 cheng2 fan4  盛汤 cheng2 tang1  盛器 cheng2 qi4  盛满 cheng2 man3
+盛放 cheng2 fang4
+
+丧事 sang1 shi4  丧礼 sang1 li3  丧葬 sang1 zang4  丧服 sang1 fu2
+治丧 zhi4 sang1  奔丧 ben1 sang1  丧钟 sang1 zhong1  发丧 fa1 sang1
+吊丧 diao4 sang1  丧家 sang1 jia1  丧家之犬 sang4 jia1 zhi1 quan3  丧偶 sang4 ou3
+报丧 bao4 sang1  守丧 shou3 sang1  丧葬费 sang1 zang4 fei4
+
+折本 she2 ben3  折腾 zhe1 teng  折跟头 zhe1 gen1 tou  瞎折腾 xia1 zhe1 teng
+折耗 she2 hao4  亏折 kui1 she2
+
+仿佛 fang3 fu2
+
+咽喉 yan1 hou2  咽炎 yan1 yan2  咽部 yan1 bu4  咽头 yan1 tou2  哽咽 geng3 ye4
+呜咽 wu1 ye4  鼻咽 bi2 yan1  咽喉炎 yan1 hou2 yan2  鼻咽癌 bi2 yan1 ai2
+
+鲜为人知 xian3 wei2 ren2 zhi1  鲜见 xian3 jian4  鲜有 xian3 you3  鲜少 xian3 shao3
+屡见不鲜 lü3 jian4 bu4 xian1  寡廉鲜耻 gua3 lian2 xian3 chi3
+
+宿舍 su4 she4  校舍 xiao4 she4  舍下 she4 xia4  旅舍 lü3 she4  寒舍 han2 she4
+农舍 nong2 she4  房舍 fang2 she4  舍弟 she4 di4  退避三舍 tui4 bi4 san1 she4
+宿舍楼 su4 she4 lou2  学舍 xue2 she4  精舍 jing1 she4  鸡舍 ji1 she4
+猪舍 zhu1 she4  舍监 she4 jian1  茅舍 mao2 she4  竹舍 zhu2 she4
+田舍 tian2 she4  客舍 ke4 she4  舍间 she4 jian1  牛舍 niu2 she4
+
+猪圈 zhu1 juan4  羊圈 yang2 juan4  圈养 juan4 yang3  牛圈 niu2 juan4  马圈 ma3 juan4
+圈舍 juan4 she4
+
+哄骗 hong3 pian4  哄孩子 hong3 hai2 zi  起哄 qi3 hong4  一哄而散 yi1 hong4 er2 san4
+哄抢 hong1 qiang3  哄堂大笑 hong1 tang2 da4 xiao4  哄笑 hong1 xiao4  闹哄哄 nao4 hong1 hong1
+乱哄哄 luan4 hong1 hong1  一哄而上 yi1 hong4 er2 shang4  哄传 hong1 chuan2
+哄动 hong1 dong4  哄抬 hong1 tai2  哄抬物价 hong1 tai2 wu4 jia4  哄然 hong1 ran2
+
+稽首 qi3 shou3
+
+答应 da1 ying  答理 da1 li  答腔 da1 qiang1  答茬 da1 cha2  答讪 da1 shan4
+
+钻石 zuan4 shi2  钻头 zuan4 tou2  电钻 dian4 zuan4  钻戒 zuan4 jie4
+钻床 zuan4 chuang2  风钻 feng1 zuan4  钻井 zuan4 jing3  钻机 zuan4 ji1
+钻探 zuan1 tan4  钻井平台 zuan4 jing3 ping2 tai2  金刚钻 jin1 gang1 zuan4
+钻石王老五 zuan4 shi2 wang2 lao3 wu3  钻塔 zuan4 ta3  冲击钻 chong1 ji1 zuan4
+
+店铺 dian4 pu4  铺子 pu4 zi  床铺 chuang2 pu4  当铺 dang4 pu4  商铺 shang1 pu4
+铺位 pu4 wei4  卧铺 wo4 pu4  药铺 yao4 pu4  肉铺 rou4 pu4  饭铺 fan4 pu4
+杂货铺 za2 huo4 pu4  通铺 tong1 pu4  铺面 pu4 mian4  床铺位 chuang2 pu4 wei4
+硬卧铺 ying4 wo4 pu4  上铺 shang4 pu4  下铺 xia4 pu4  中铺 zhong1 pu4
+布铺 bu4 pu4  钱铺 qian2 pu4  铁匠铺 tie3 jiang4 pu4  包子铺 bao1 zi pu4
+店铺街 dian4 pu4 jie1  铺户 pu4 hu4
+
+号叫 hao2 jiao4  哀号 ai1 hao2  号啕 hao2 tao2  号哭 hao2 ku1  怒号 nu4 hao2
+号啕大哭 hao2 tao2 da4 ku1  呼号 hu1 hao2  鬼哭狼号 gui3 ku1 lang2 hao2
+北风怒号 bei3 feng1 nu4 hao2
+
+禁不住 jin1 bu zhu4  禁受 jin1 shou4  不禁 bu4 jin1  情不自禁 qing2 bu4 zi4 jin1
+弱不禁风 ruo4 bu4 jin1 feng1  忍俊不禁 ren3 jun4 bu4 jin1  禁得起 jin1 de qi3
+禁不起 jin1 bu qi3  禁得住 jin1 de zhu4  禁受不住 jin1 shou4 bu4 zhu4
+弱不禁风的 ruo4 bu4 jin1 feng1 de
+
+涨红 zhang4 hong2  头昏脑涨 tou2 hun1 nao3 zhang4  涨红了脸 zhang4 hong2 le lian3
+
+拮据 jie2 ju1
+
+喝彩 he4 cai3  吆喝 yao1 he  喝令 he4 ling4  喝倒彩 he4 dao4 cai3
+大喝一声 da4 he4 yi1 sheng1  喝问 he4 wen4  当头棒喝 dang1 tou2 bang4 he4
+喝斥 he4 chi4  喝止 he4 zhi3  呼幺喝六 hu1 yao1 he4 liu4
+
+扒手 pa2 shou3  扒窃 pa2 qie4  扒鸡 pa2 ji1  扒糕 pa2 gao1
+
+负荷 fu4 he4  荷枪实弹 he4 qiang1 shi2 dan4  电荷 dian4 he4  荷载 he4 zai4
+负荷量 fu4 he4 liang4  感荷 gan3 he4  超负荷 chao1 fu4 he4
+
+蛮横 man2 heng4  横财 heng4 cai2  专横 zhuan1 heng4  横祸 heng4 huo4
+飞来横祸 fei1 lai2 heng4 huo4  专横跋扈 zhuan1 heng4 ba2 hu4  横死 heng4 si3
+发横财 fa1 heng4 cai2  强横 qiang2 heng4  凶横 xiong1 heng4
+
+哗啦 hua1 la1  哗哗 hua1 hua1  哗啦啦 hua1 la1 la1
+
+混蛋 hun2 dan4  混水摸鱼 hun2 shui3 mo1 yu2  浑水摸鱼 hun2 shui3 mo1 yu2
+
+豁口 huo1 kou3  豁出去 huo1 chu1 qu4  豁嘴 huo1 zui3  豁出 huo1 chu1
+
+奇数 ji1 shu4  奇偶 ji1 ou3  奇偶性 ji1 ou3 xing4
+
+济南 ji3 nan2  济济 ji3 ji3  人才济济 ren2 cai2 ji3 ji3  济宁 ji3 ning2
+济济一堂 ji3 ji3 yi1 tang2
+
+间隔 jian4 ge2  间断 jian4 duan4  间接 jian4 jie1  间谍 jian4 die2
+离间 li2 jian4  反间 fan3 jian4  间歇 jian4 xie1  间或 jian4 huo4
+挑拨离间 tiao3 bo1 li2 jian4  间隙 jian4 xi4  间距 jian4 ju4  黑白相间 hei1 bai2 xiang1 jian4
+间作 jian4 zuo4  间苗 jian4 miao2  间断性 jian4 duan4 xing4  间接性 jian4 jie1 xing4
+反间计 fan3 jian4 ji4  间谍罪 jian4 die2 zui4  无间 wu2 jian4  亲密无间 qin1 mi4 wu2 jian4
+间歇性 jian4 xie1 xing4  间隔期 jian4 ge2 qi1  间种 jian4 zhong4
+
+太监 tai4 jian4  国子监 guo2 zi3 jian4  钦天监 qin1 tian1 jian4
+
+咀嚼 ju3 jue2  过屠门而大嚼 guo4 tu2 men2 er2 da4 jue2
+
+校对 jiao4 dui4  校正 jiao4 zheng4  校订 jiao4 ding4  校勘 jiao4 kan1
+校准 jiao4 zhun3  校样 jiao4 yang4  校验 jiao4 yan4  校注 jiao4 zhu4
+校点 jiao4 dian3  校阅 jiao4 yue4  校场 jiao4 chang3  校对员 jiao4 dui4 yuan2
+校验码 jiao4 yan4 ma3  点校 dian3 jiao4  审校 shen3 jiao4  复校 fu4 jiao4
+
+节骨眼 jie1 gu yan3  节骨眼儿 jie1 gu yan3 er
+
+强劲 qiang2 jing4  劲敌 jing4 di2  劲旅 jing4 lü3  刚劲 gang1 jing4
+劲松 jing4 song1  劲草 jing4 cao3  苍劲 cang1 jing4  劲射 jing4 she4
+遒劲 qiu2 jing4  疾风劲草 ji2 feng1 jing4 cao3  强劲有力 qiang2 jing4 you3 li4
+劲爆 jing4 bao4  劲歌 jing4 ge1  劲舞 jing4 wu3  劲拔 jing4 ba2
+
+脖颈 bo2 geng3  脖颈儿 bo2 geng3 er
+
+试卷 shi4 juan4  考卷 kao3 juan4  答卷 da2 juan4  卷宗 juan4 zong1
+画卷 hua4 juan4  案卷 an4 juan4  手不释卷 shou3 bu4 shi4 juan4
+开卷有益 kai1 juan4 you3 yi4  卷帙 juan4 zhi4  问卷 wen4 juan4
+问卷调查 wen4 juan4 diao4 cha2  卷轴 juan4 zhou2  书卷 shu1 juan4
+交白卷 jiao1 bai2 juan4  白卷 bai2 juan4  阅卷 yue4 juan4  卷面 juan4 mian4
+上卷 shang4 juan4  下卷 xia4 juan4  开卷 kai1 juan4  闭卷 bi4 juan4
+卷帙浩繁 juan4 zhi4 hao4 fan2  书卷气 shu1 juan4 qi4  长卷 chang2 juan4
+
+咳声叹气 hai1 sheng1 tan4 qi4
+
+可汗 ke4 han2
+
+勒紧 lei1 jin3  勒死 lei1 si3  勒住 lei1 zhu4  勒紧裤腰带 lei1 jin3 ku4 yao1 dai4
+
+积累 ji1 lei3  累计 lei3 ji4  日积月累 ri4 ji1 yue4 lei3  连累 lian2 lei3
+累积 lei3 ji1  牵累 qian1 lei3  累及 lei3 ji2  累累 lei3 lei3
+累赘 lei2 zhui  果实累累 guo3 shi2 lei2 lei2  罪行累累 zui4 xing2 lei3 lei3
+累进 lei3 jin4  累犯 lei3 fan4  累加 lei3 jia1  积累性 ji1 lei3 xing4
+伤痕累累 shang1 hen2 lei3 lei3  危如累卵 wei1 ru2 lei3 luan3  经年累月 jing1 nian2 lei3 yue4
+长年累月 chang2 nian2 lei3 yue4  连篇累牍 lian2 pian1 lei3 du2  累年 lei3 nian2
+拖累 tuo1 lei3  负累 fu4 lei3  带累 dai4 lei3  家累 jia1 lei3  累世 lei3 shi4
+累次 lei3 ci4  累计数 lei3 ji4 shu4  连累到 lian2 lei3 dao4
+
+淋病 lin4 bing4  过淋 guo4 lin4
+
+一溜烟 yi1 liu4 yan1  一溜 yi1 liu4
+
+绿林 lu4 lin2  鸭绿江 ya1 lu4 jiang1  绿林好汉 lu4 lin2 hao3 han4
+
+论语 lun2 yu3
+
+埋怨 man2 yuan4
+
+秘鲁 bi4 lu3
+
+婀娜 e1 nuo2  袅娜 niao3 nuo2  婀娜多姿 e1 nuo2 duo1 zi1
+
+宁可 ning4 ke3  宁愿 ning4 yuan4  宁肯 ning4 ken3  宁死不屈 ning4 si3 bu4 qu1
+毋宁 wu2 ning4  宁缺毋滥 ning4 que1 wu2 lan4  宁为玉碎 ning4 wei2 yu4 sui4
+宁可信其有 ning4 ke3 xin4 qi2 you3  宁折不弯 ning4 zhe2 bu4 wan1
+
+弄堂 long4 tang2  里弄 li3 long4
+
+刨子 bao4 zi  刨床 bao4 chuang2  刨冰 bao4 bing1  刨花 bao4 hua1  刨刀 bao4 dao1
+刨平 bao4 ping2  刨花板 bao4 hua1 ban3
+
+喷香 pen4 xiang1  喷喷香 pen4 pen4 xiang1
+
+漂白 piao3 bai2  漂洗 piao3 xi3  漂白粉 piao3 bai2 fen3  漂白剂 piao3 bai2 ji4
+漂亮 piao4 liang  漂漂亮亮 piao4 piao4 liang4 liang4
+
+前仆后继 qian2 pu1 hou4 ji4  仆倒 pu1 dao3
+
+雪茄 xue3 jia1  雪茄烟 xue3 jia1 yan1
+
+一切 yi1 qie4  切实 qie4 shi2  亲切 qin1 qie4  密切 mi4 qie4  迫切 po4 qie4
+急切 ji2 qie4  确切 que4 qie4  切记 qie4 ji4  切忌 qie4 ji4  切身 qie4 shen1
+贴切 tie1 qie4  恳切 ken3 qie4  真切 zhen1 qie4  殷切 yin1 qie4  关切 guan1 qie4
+热切 re4 qie4  切合 qie4 he2  切中 qie4 zhong4  悲切 bei1 qie4  切勿 qie4 wu4
+不顾一切 bu4 gu4 yi1 qie4  切实可行 qie4 shi2 ke3 xing2  切身利益 qie4 shen1 li4 yi4
+切齿 qie4 chi3  咬牙切齿 yao3 ya2 qie4 chi3  切肤之痛 qie4 fu1 zhi1 tong4
+一切都 yi1 qie4 dou1  切切 qie4 qie4  凄切 qi1 qie4  痛切 tong4 qie4
+深切 shen1 qie4  一切的 yi1 qie4 de  切要 qie4 yao4  切题 qie4 ti2
+切合实际 qie4 he2 shi2 ji4  切脉 qie4 mai4  心切 xin1 qie4  求胜心切 qiu2 sheng4 xin1 qie4
+急切地 ji2 qie4 de  密切相关 mi4 qie4 xiang1 guan1  密切合作 mi4 qie4 he2 zuo4
+迫切需要 po4 qie4 xu1 yao4  切实加强 qie4 shi2 jia1 qiang2  切中要害 qie4 zhong4 yao4 hai4
+一切从实际出发 yi1 qie4 cong2 shi2 ji4 chu1 fa1
+
+亲家 qing4 jia  亲家母 qing4 jia mu3  亲家公 qing4 jia gong1
+
+厦门 xia4 men2  厦门大学 xia4 men2 da4 xue2  厦门市 xia4 men2 shi4
+
+什锦 shi2 jin3  什物 shi2 wu4  家什 jia1 shi
+
+标识 biao1 zhi4  博闻强识 bo2 wen2 qiang2 zhi4  款识 kuan3 zhi4
+
+属意 zhu3 yi4  属望 zhu3 wang4  属文 zhu3 wen2
+
+游说 you2 shui4  说客 shui4 ke4
+
+半身不遂 ban4 shen1 bu4 sui2
+
+一沓 yi1 da2
+
+舌苔 she2 tai1
+
+提防 di1 fang  提溜 di1 liu
+
+字帖 zi4 tie4  碑帖 bei1 tie4  画帖 hua4 tie4  请帖 qing3 tie3  帖子 tie3 zi
+喜帖 xi3 tie3  发帖 fa1 tie3  回帖 hui2 tie3  跟帖 gen1 tie3  名帖 ming2 tie3
+请帖儿 qing3 tie3 er  庚帖 geng1 tie3  字帖儿 zi4 tie4 er  主帖 zhu3 tie3
+原帖 yuan2 tie3  帖吧 tie3 ba1  帖文 tie3 wen2
+
+胡同 hu2 tong4  胡同儿 hu2 tong4 er
+
+呕吐 ou3 tu4  上吐下泻 shang4 tu4 xia4 xie4  吐血 tu4 xie3  吐沫 tu4 mo
+吐沫星子 tu4 mo xing1 zi  吐白沫 tu4 bai2 mo4
+
+拓本 ta4 ben3  拓片 ta4 pian4  拓印 ta4 yin4
+
+纤夫 qian4 fu1  拉纤 la1 qian4  纤绳 qian4 sheng2
+
+巷道 hang4 dao4
+
+压根 ya4 gen1  压根儿 ya4 gen1 er
+
+轧钢 zha2 gang1  轧钢厂 zha2 gang1 chang3  轧机 zha2 ji1  轧辊 zha2 gun3
+
+燕山 yan1 shan1  燕京 yan1 jing1  燕赵 yan1 zhao4  燕国 yan1 guo2
+燕京大学 yan1 jing1 da4 xue2  燕园 yan1 yuan2
+
+要求 yao1 qiu2  要挟 yao1 xie2  要求者 yao1 qiu2 zhe3  要求书 yao1 qiu2 shu1
+
+锁钥 suo3 yue4
+
+佣金 yong4 jin1  佣钱 yong4 qian2
+
+参与 can1 yu4  与会 yu4 hui4  与闻 yu4 wen2  参与者 can1 yu4 zhe3
+与会者 yu4 hui4 zhe3  参与度 can1 yu4 du4  参与性 can1 yu4 xing4
+
+熨帖 yu4 tie1
+
+晕车 yun4 che1  晕船 yun4 chuan2  晕机 yun4 ji1  光晕 guang1 yun4
+红晕 hong2 yun4  日晕 ri4 yun4  月晕 yue4 yun4  晕车药 yun4 che1 yao4
+眼晕 yan3 yun4  晕针 yun4 zhen1  晕血 yun4 xue4  晕高 yun4 gao1
+
+炸酱面 zha2 jiang4 mian4  油炸 you2 zha2  炸鸡 zha2 ji1  炸薯条 zha2 shu3 tiao2
+炸丸子 zha2 wan2 zi  炸油条 zha2 you2 tiao2  炸酱 zha2 jiang4  炸糕 zha2 gao1
+炸鱼 zha2 yu2  炸虾 zha2 xia1  炸鸡块 zha2 ji1 kuai4  干炸 gan1 zha2
+软炸 ruan3 zha2  炸串 zha2 chuan4  炸春卷 zha2 chun1 juan3  炸鸡腿 zha2 ji1 tui3
+
+粘稠 nian2 chou2  粘液 nian2 ye4  粘性 nian2 xing4  粘土 nian2 tu3
+粘膜 nian2 mo2  粘连 nian2 lian2  粘度 nian2 du4  粘合 nian2 he2
+粘合剂 nian2 he2 ji4  粘糊 nian2 hu  粘糊糊 nian2 hu1 hu1  粘米 nian2 mi3
+
+占卜 zhan1 bu3  占卦 zhan1 gua4  占星 zhan1 xing1  占星术 zhan1 xing1 shu4
+
+爪牙 zhao3 ya2
+
+症结 zheng1 jie2
+
+骨殖 gu3 shi
+
+作坊 zuo1 fang  手工作坊 shou3 gong1 zuo1 fang  作坊式 zuo1 fang shi4
+
+担子 dan4 zi  重担 zhong4 dan4  扁担 bian3 dan  担担面 dan4 dan4 mian4
+挑担子 tiao1 dan4 zi  货郎担 huo4 lang2 dan4  千斤重担 qian1 jin1 zhong4 dan4
+勇挑重担 yong3 tiao1 zhong4 dan4  担担 dan4 dan4
+
+待会儿 dai1 hui4 er  待一会 dai1 yi1 hui4  待会 dai1 hui4  待着 dai1 zhe
+
+揣度 chuai3 duo2  忖度 cun3 duo2  度德量力 duo2 de2 liang4 li4
+
+粮囤 liang2 dun4
+
+磨坊 mo4 fang2  染坊 ran3 fang2  油坊 you2 fang2  粉坊 fen3 fang2
+酒坊 jiu3 fang2  作坊主 zuo1 fang zhu3  碾坊 nian3 fang2
+
+缝隙 feng4 xi4  裂缝 lie4 feng4  门缝 men2 feng4  天衣无缝 tian1 yi1 wu2 feng4
+见缝插针 jian4 feng4 cha1 zhen1  无缝 wu2 feng4  墙缝 qiang2 feng4  地缝 di4 feng4
+缝儿 feng4 er  石缝 shi2 feng4  针线缝 zhen1 xian4 feng4  窄缝 zhai3 feng4
+无缝钢管 wu2 feng4 gang1 guan3  无缝对接 wu2 feng4 dui4 jie1  夹缝 jia1 feng4
+夹缝中 jia1 feng4 zhong1  牙缝 ya2 feng4  指缝 zhi3 feng4  骑缝 qi2 feng4
+
+胸脯 xiong1 pu2  果脯 guo3 fu3  肉脯 rou4 fu3
+
+旗杆 qi2 gan1  电线杆 dian4 xian4 gan1  栏杆 lan2 gan1  桅杆 wei2 gan1
+杆子 gan1 zi  电杆 dian4 gan1  标杆 biao1 gan1  木杆 mu4 gan1  竹杆 zhu2 gan1
+电线杆子 dian4 xian4 gan1 zi
+
+诸葛 zhu1 ge3  诸葛亮 zhu1 ge3 liang4  诸葛孔明 zhu1 ge3 kong3 ming2
+
+自个儿 zi4 ge3 er  自个 zi4 ge3
+
+骨朵 gu1 duo  骨碌 gu1 lu  花骨朵 hua1 gu1 duo
+
+道观 dao4 guan4  寺观 si4 guan4  白云观 bai2 yun2 guan4
+
+冠军 guan4 jun1  冠名 guan4 ming2  夺冠 duo2 guan4  亚冠 ya4 guan4
+冠亚军 guan4 ya4 jun1  卫冕冠军 wei4 mian3 guan4 jun1  冠以 guan4 yi3
+冠军赛 guan4 jun1 sai4  冠名权 guan4 ming2 quan2  冠绝 guan4 jue2
+世界冠军 shi4 jie4 guan4 jun1  总冠军 zong3 guan4 jun1  全国冠军 quan2 guo2 guan4 jun1
+奥运冠军 ao4 yun4 guan4 jun1  冠军杯 guan4 jun1 bei1  三连冠 san1 lian2 guan4
+卫冕 wei4 mian3  冠军头衔 guan4 jun1 tou2 xian2
+
+龟裂 jun1 lie4
+
+女红 nü3 gong1
+
+糊弄 hu4 nong  糊弄人 hu4 nong ren2
+
+划船 hua2 chuan2  划算 hua2 suan4  划得来 hua2 de lai2  划不来 hua2 bu lai2
+划桨 hua2 jiang3  划艇 hua2 ting3  划拳 hua2 quan2  划水 hua2 shui3
+划痕 hua2 hen2  划伤 hua2 shang1  划破 hua2 po4  划开 hua2 kai1
+划火柴 hua2 huo3 chai2  划龙舟 hua2 long2 zhou1  划子 hua2 zi  赛龙舟 sai4 long2 zhou1
+
+会计 kuai4 ji4  会计师 kuai4 ji4 shi1  财会 cai2 kuai4  会计学 kuai4 ji4 xue2
+会计师事务所 kuai4 ji4 shi1 shi4 wu4 suo3  总会计师 zong3 kuai4 ji4 shi1
+会计制度 kuai4 ji4 zhi4 du4  会计准则 kuai4 ji4 zhun3 ze2  会计科目 kuai4 ji4 ke1 mu4
+
+夹袄 jia2 ao3  夹被 jia2 bei4  夹衣 jia2 yi1
+
+伎俩 ji4 liang3
+
+笼统 long3 tong3  笼罩 long3 zhao4  笼络 long3 luo4  笼络人心 long3 luo4 ren2 xin1
+笼罩着 long3 zhao4 zhe
+
+抹布 ma1 bu4
+
+迫击炮 pai3 ji1 pao4
+
+悄然 qiao3 ran2  悄声 qiao3 sheng1  悄然无声 qiao3 ran2 wu2 sheng1  悄然而至 qiao3 ran2 er2 zhi4
+
+翘首 qiao2 shou3  翘楚 qiao2 chu3  翘首以待 qiao2 shou3 yi3 dai4  翘首企盼 qiao2 shou3 qi3 pan4
+
+扫帚 sao4 zhou  扫帚星 sao4 zhou xing1
+
+杉木 sha1 mu4
+
+稍息 shao4 xi1
+
+踏实 ta1 shi  踏踏实实 ta1 ta1 shi2 shi2
+
+委蛇 wei1 yi2  虚与委蛇 xu1 yu3 wei1 yi2
+
+尉迟 yu4 chi2
+
+殷红 yan1 hong2
+
+哈达 ha3 da2  哈巴狗 ha3 ba1 gou3
+
+阿胶 e1 jiao1  阿谀 e1 yu2  阿谀奉承 e1 yu2 feng4 cheng2  阿房宫 e1 pang2 gong1
+
+执拗 zhi2 niu4  拗不过 niu4 bu guo4
+
+刀把 dao1 ba4  把子 ba4 zi  枪把 qiang1 ba4  话把 hua4 ba4  印把子 yin4 ba4 zi
+
+蚌埠 beng4 bu4
+
+堡子 bu3 zi
+
+一暴十寒 yi1 pu4 shi2 han2
+
+背包 bei1 bao1  背负 bei1 fu4  背黑锅 bei1 hei1 guo1  背债 bei1 zhai4
+背着 bei1 zhe  背包袱 bei1 bao1 fu  背起 bei1 qi3  背上 bei1 shang4
+背包客 bei1 bao1 ke4  背带 bei1 dai4  背篓 bei1 lou3  背书包 bei1 shu1 bao1
+
+胳臂 ge1 bei
+
+扁舟 pian1 zhou1  一叶扁舟 yi1 ye4 pian1 zhou1
+
+簸箕 bo4 ji
+
+萝卜 luo2 bo  胡萝卜 hu2 luo2 bo  白萝卜 bai2 luo2 bo  红萝卜 hong2 luo2 bo
+萝卜干 luo2 bo gan1  萝卜头 luo2 bo tou2  萝卜丝 luo2 bo si1
+
+禅让 shan4 rang4  封禅 feng1 shan4
+
+颤栗 zhan4 li4  打颤 da3 zhan4  战栗 zhan4 li4  颤抖 chan4 dou3
+
+场院 chang2 yuan4  一场雨 yi1 chang2 yu3  赶场 gan3 chang2
+
+钥匙 yao4 shi  汤匙 tang1 chi2  茶匙 cha2 chi2  羹匙 geng1 chi2  钥匙扣 yao4 shi kou4
+钥匙链 yao4 shi lian4  钥匙孔 yao4 shi kong3
+
+乳臭未干 ru3 xiu4 wei4 gan1  无色无臭 wu2 se4 wu2 xiu4  铜臭 tong2 xiu4  乳臭 ru3 xiu4
+其臭如兰 qi2 xiu4 ru2 lan2
+
+伺候 ci4 hou
+
+攒动 cuan2 dong4  人头攒动 ren2 tou2 cuan2 dong4
+
+钉住 ding4 zhu4  钉扣子 ding4 kou4 zi  钉上 ding4 shang4  钉钉子 ding4 ding1 zi
+
+否极泰来 pi3 ji2 tai4 lai2  臧否 zang1 pi3
+
+蛤蜊 ge2 li2  蛤蚧 ge2 jie4  花蛤 hua1 ge2
+
+勾当 gou4 dang4
+
+吐谷浑 tu3 yu4 hun2
+
+东莞 dong1 guan3  东莞市 dong1 guan3 shi4
+
+秦桧 qin2 hui4
+
+虾蟆 ha2 ma
+
+引吭高歌 yin3 hang2 gao1 ge1
+
+孩子 hai2 zi  儿子 er2 zi  妻子 qi1 zi  桌子 zhuo1 zi  椅子 yi3 zi  房子 fang2 zi
+日子 ri4 zi  样子 yang4 zi  句子 ju4 zi  鼻子 bi2 zi  杯子 bei1 zi  本子 ben3 zi
+被子 bei4 zi  箱子 xiang1 zi  帽子 mao4 zi  裙子 qun2 zi  裤子 ku4 zi
+鞋子 xie2 zi  袜子 wa4 zi  院子 yuan4 zi  村子 cun1 zi  镜子 jing4 zi
+胖子 pang4 zi  瘦子 shou4 zi  傻子 sha3 zi  疯子 feng1 zi  骗子 pian4 zi
+老子 lao3 zi  小子 xiao3 zi  嫂子 sao3 zi  孙子 sun1 zi  侄子 zhi2 zi
+兔子 tu4 zi  猴子 hou2 zi  狮子 shi1 zi  燕子 yan4 zi  鸽子 ge1 zi
+蚊子 wen2 zi  虫子 chong2 zi  叶子 ye4 zi  果子 guo3 zi  种子 zhong3 zi
+李子 li3 zi  桃子 tao2 zi  橘子 ju2 zi  柿子 shi4 zi  饺子 jiao3 zi
+包子 bao1 zi  面子 mian4 zi  法子 fa3 zi  点子 dian3 zi  脑子 nao3 zi
+肚子 du4 zi  嗓子 sang3 zi  胡子 hu2 zi  个子 ge4 zi  身子 shen1 zi
+辫子 bian4 zi  盒子 he2 zi  盘子 pan2 zi  瓶子 ping2 zi  筷子 kuai4 zi
+刀子 dao1 zi  剪子 jian3 zi  锤子 chui2 zi  钉子 ding1 zi  绳子 sheng2 zi
+轮子 lun2 zi  车子 che1 zi  毯子 tan3 zi  席子 xi2 zi  柜子 gui4 zi
+窗子 chuang1 zi  屋子 wu1 zi  亭子 ting2 zi  摊子 tan1 zi  铺子 pu4 zi
+班子 ban1 zi  圈子 quan1 zi  稿子 gao3 zi  段子 duan4 zi  调子 diao4 zi
+架子 jia4 zi  底子 di3 zi  影子 ying3 zi  样子货 yang4 zi huo4  日子里 ri4 zi li3
+一下子 yi1 xia4 zi  一辈子 yi1 bei4 zi  一阵子 yi1 zhen4 zi  这辈子 zhe4 bei4 zi
+小伙子 xiao3 huo3 zi  老头子 lao3 tou2 zi  老婆子 lao3 po2 zi  小孩子 xiao3 hai2 zi
+男孩子 nan2 hai2 zi  女孩子 nü3 hai2 zi  孩子们 hai2 zi men  儿子们 er2 zi men
+妻子儿女 qi1 zi3 er2 nü3
+`;
+
+const isHan = (c) => /\p{Script=Han}/u.test(c);
+const isSyllable = (reading) => /^[a-zü]+[1-4]?$/.test(reading);
+
+const pairs = (text) =>
+  text
+    .split("\n")
+    .flatMap((line) => line.trim().split(/ {2,}/))
+    .filter((entry) => entry !== "")
+    .map((entry) => {
+      const [word, ...readings] = entry.split(" ");
+      if (Array.from(word).length !== readings.length || !readings.every(isSyllable)) {
+        throw new Error(`invalid entry: ${entry}`);
+      }
+      return [word, readings];
+    });
+
+const chars = [];
+for (let code = 0x3400; code <= 0x2fa1f; code++) {
+  const c = String.fromCodePoint(code);
+  if (isHan(c)) chars.push(c);
+}
+
+const { stdout } = new Deno.Command("uconv", {
+  args: ["-x", "Han-Latin; Latin-NumericPinyin"],
+  stdin: "piped",
+  stdout: "piped",
+}).outputSync({ input: chars.join("\n") + "\n" });
+const transformed = new TextDecoder().decode(stdout).split("\n");
+
+const readings = new Map();
+chars.forEach((c, i) => isSyllable(transformed[i]) && readings.set(c, transformed[i]));
+for (const [c, [reading]] of pairs(CHARS)) readings.set(c, reading);
+
+// characters grouped by reading, as `reading<TAB>characters` lines
+const groups = new Map();
+for (const [c, reading] of readings) groups.set(reading, (groups.get(reading) ?? "") + c);
+const table = [...groups].sort(([a], [b]) => (a < b ? -1 : 1));
+
+// words only kept when read differently than character for character
+const words = new Map();
+for (const [word, syllables] of pairs(WORDS)) {
+  const single = Array.from(word).length === 1;
+  if (single || Array.from(word).some((c, i) => readings.get(c) !== syllables[i])) {
+    words.set(word, syllables.join(" "));
+  }
+}
+
+Deno.writeTextFileSync(
+  "src/data/pinyin.txt",
+  table.map(([reading, chars]) => `${reading}\t${chars}`).join("\n") + "\n",
+);
+Deno.writeTextFileSync(
+  "src/data/pinyin_words.txt",
+  [...words].map(([word, syllables]) => `${word}\t${syllables}`).join("\n") + "\n",
+);
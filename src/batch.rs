@@ -0,0 +1,70 @@
+use std::{error, fmt};
+
+use crate::types::Token;
+
+/// The document lengths do not match the concatenated text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchError {
+    /// The document at this index runs past the text or splits a character
+    InvalidLength(usize),
+    /// The documents do not cover the whole text
+    TrailingText,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::InvalidLength(document) => write!(
+                f,
+                "document {} does not end on a character boundary of the text",
+                document
+            ),
+            BatchError::TrailingText => {
+                write!(f, "the document lengths do not cover the whole text")
+            }
+        }
+    }
+}
+
+impl error::Error for BatchError {}
+
+/// Split the concatenated text of a batch back into its documents,
+/// given their lengths in UTF-16 code units
+pub(crate) fn split_documents<'a>(
+    text: &'a str,
+    lengths: &[u32],
+) -> Result<Vec<&'a str>, BatchError> {
+    let mut documents = Vec::with_capacity(lengths.len());
+    let mut chars = text.char_indices();
+    let mut start = 0;
+
+    for (document, &length) in lengths.iter().enumerate() {
+        let mut units = 0;
+        let mut end = start;
+        while units < length as usize {
+            let (index, c) = chars.next().ok_or(BatchError::InvalidLength(document))?;
+            units += c.len_utf16();
+            end = index + c.len_utf8();
+        }
+        if units != length as usize {
+            return Err(BatchError::InvalidLength(document));
+        }
+        documents.push(&text[start..end]);
+        start = end;
+    }
+
+    if start != text.len() {
+        return Err(BatchError::TrailingText);
+    }
+
+    Ok(documents)
+}
+
+/// Append the tokens of a document as `count, (start, end)*`
+pub(crate) fn encode_tokens(tokens: &[Token], output: &mut Vec<u32>) {
+    output.push(tokens.len() as u32);
+    for token in tokens {
+        output.push(token.start as u32);
+        output.push(token.end as u32);
+    }
+}
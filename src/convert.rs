@@ -0,0 +1,71 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// Simplified to Traditional Chinese, generated by `scripts/opencc.js`
+static S2T: &str = include_str!("data/s2t.txt");
+/// Traditional to Simplified Chinese, generated by `scripts/opencc.js`
+static T2S: &str = include_str!("data/t2s.txt");
+
+lazy_static! {
+    static ref S2T_TABLE: ConversionTable = ConversionTable::parse(S2T);
+    static ref T2S_TABLE: ConversionTable = ConversionTable::parse(T2S);
+}
+
+/// Characters and phrases with what they convert to, always as many
+/// characters as they have
+struct ConversionTable {
+    entries: HashMap<&'static str, &'static str>,
+    /// Length of the longest phrase, in chars
+    longest: usize,
+}
+
+impl ConversionTable {
+    fn parse(table: &'static str) -> Self {
+        let entries: HashMap<_, _> = table
+            .lines()
+            .map(|line| line.split_once('\t').expect("invalid conversion table"))
+            .collect();
+        let longest = entries
+            .keys()
+            .map(|from| from.chars().count())
+            .max()
+            .unwrap_or(1);
+        ConversionTable { entries, longest }
+    }
+
+    /// Convert a text the way OpenCC does, taking the longest phrase in the
+    /// table at every position
+    fn convert(&self, text: &str) -> String {
+        let bounds = text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(text.len()))
+            .collect::<Vec<_>>();
+        let mut converted = String::with_capacity(text.len());
+        let mut i = 0;
+
+        while i + 1 < bounds.len() {
+            let longest = self.longest.min(bounds.len() - 1 - i);
+            let (n, to) = (1..=longest)
+                .rev()
+                .find_map(|n| {
+                    let from = &text[bounds[i]..bounds[i + n]];
+                    self.entries.get(from).map(|&to| (n, to))
+                })
+                .unwrap_or((1, &text[bounds[i]..bounds[i + 1]]));
+            converted.push_str(to);
+            i += n;
+        }
+        converted
+    }
+}
+
+/// Convert Traditional Chinese to Simplified Chinese, character for character
+pub(crate) fn to_simplified(text: &str) -> String {
+    T2S_TABLE.convert(text)
+}
+
+/// Convert Simplified Chinese to Traditional Chinese, character for character
+pub(crate) fn to_traditional(text: &str) -> String {
+    S2T_TABLE.convert(text)
+}
@@ -0,0 +1,204 @@
+use std::{error, fmt};
+
+use crate::normalize::is_zero_width;
+
+/// A parsed line of a dictionary in jieba's `word freq tag` format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DictEntry {
+    pub word: String,
+    pub freq: Option<usize>,
+    pub tag: Option<String>,
+    /// The line is a `word -` removal, as written by delta exports
+    pub remove: bool,
+}
+
+/// The reason a dictionary line was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DictErrorKind {
+    /// The line is not valid UTF-8
+    InvalidUtf8,
+    /// The word is empty once invisible characters are removed
+    EmptyWord,
+    /// The frequency is not a non-negative integer
+    InvalidFrequency(String),
+    /// The IDF value is missing
+    MissingIdf,
+    /// The IDF value is not a finite number
+    InvalidIdf(String),
+}
+
+/// Invalid entry in a dictionary, with the 1-based line number and its text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictError {
+    pub line: usize,
+    pub text: String,
+    pub kind: DictErrorKind,
+}
+
+impl fmt::Display for DictErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictErrorKind::InvalidUtf8 => write!(f, "invalid UTF-8"),
+            DictErrorKind::EmptyWord => write!(f, "empty word"),
+            DictErrorKind::InvalidFrequency(freq) => {
+                write!(f, "frequency `{}` is not a valid integer", freq)
+            }
+            DictErrorKind::MissingIdf => write!(f, "missing idf"),
+            DictErrorKind::InvalidIdf(idf) => write!(f, "idf `{}` is not a valid number", idf),
+        }
+    }
+}
+
+impl fmt::Display for DictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} `{}`: {}", self.line, self.text, self.kind)
+    }
+}
+
+impl error::Error for DictError {}
+
+/// Second field of a dictionary line that removes the word before it
+pub(crate) const REMOVAL_MARKER: &str = "-";
+
+#[inline]
+fn is_invisible(c: char) -> bool {
+    is_zero_width(c) || c.is_control()
+}
+
+/// Parse a whole dictionary, failing on the first invalid line.
+///
+/// Blank lines are skipped, and a leading byte order mark is ignored. A word
+/// followed by a lone `-` is marked as removed; it cannot be a normal line,
+/// whose second field is a frequency.
+pub(crate) fn parse_dict(buf: &[u8]) -> Result<Vec<DictEntry>, DictError> {
+    let mut entries = Vec::new();
+
+    for (index, raw) in buf.split(|&b| b == b'\n').enumerate() {
+        let line = index + 1;
+        let text = std::str::from_utf8(raw).map_err(|_| DictError {
+            line,
+            text: String::from_utf8_lossy(raw).trim().into(),
+            kind: DictErrorKind::InvalidUtf8,
+        })?;
+        let text = if line == 1 {
+            text.trim_start_matches('\u{feff}')
+        } else {
+            text
+        };
+
+        let mut iter = text.split_whitespace();
+        let word = match iter.next() {
+            Some(word) => word,
+            None => continue,
+        };
+        let error = |kind| DictError {
+            line,
+            text: text.trim().into(),
+            kind,
+        };
+
+        if word.chars().all(is_invisible) {
+            return Err(error(DictErrorKind::EmptyWord));
+        }
+
+        let freq = iter.next();
+        if freq == Some(REMOVAL_MARKER) && iter.clone().next().is_none() {
+            entries.push(DictEntry {
+                word: word.into(),
+                freq: None,
+                tag: None,
+                remove: true,
+            });
+            continue;
+        }
+
+        let freq = freq
+            .map(|freq| {
+                freq.parse::<usize>()
+                    .map_err(|_| error(DictErrorKind::InvalidFrequency(freq.into())))
+            })
+            .transpose()?;
+        let tag = iter.next().map(String::from);
+
+        entries.push(DictEntry {
+            word: word.into(),
+            freq,
+            tag,
+            remove: false,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Parse a list of words, one per line, failing on the first invalid line.
+///
+/// Blank lines are skipped, and a leading byte order mark is ignored.
+pub(crate) fn parse_word_list(buf: &[u8]) -> Result<Vec<String>, DictError> {
+    let mut words = Vec::new();
+
+    for (index, raw) in buf.split(|&b| b == b'\n').enumerate() {
+        let line = index + 1;
+        let text = std::str::from_utf8(raw).map_err(|_| DictError {
+            line,
+            text: String::from_utf8_lossy(raw).trim().into(),
+            kind: DictErrorKind::InvalidUtf8,
+        })?;
+        let word = text.trim().trim_start_matches('\u{feff}');
+
+        if !word.is_empty() {
+            words.push(word.into());
+        }
+    }
+
+    Ok(words)
+}
+
+/// Parse an IDF table in `word idf` format, failing on the first invalid line.
+///
+/// Blank lines are skipped, and a leading byte order mark is ignored.
+pub(crate) fn parse_idf(buf: &[u8]) -> Result<Vec<(String, f64)>, DictError> {
+    let mut entries = Vec::new();
+
+    for (index, raw) in buf.split(|&b| b == b'\n').enumerate() {
+        let line = index + 1;
+        let text = std::str::from_utf8(raw).map_err(|_| DictError {
+            line,
+            text: String::from_utf8_lossy(raw).trim().into(),
+            kind: DictErrorKind::InvalidUtf8,
+        })?;
+        let text = if line == 1 {
+            text.trim_start_matches('\u{feff}')
+        } else {
+            text
+        };
+
+        let mut iter = text.split_whitespace();
+        let word = match iter.next() {
+            Some(word) => word,
+            None => continue,
+        };
+        let error = |kind| DictError {
+            line,
+            text: text.trim().into(),
+            kind,
+        };
+
+        if word.chars().all(is_invisible) {
+            return Err(error(DictErrorKind::EmptyWord));
+        }
+
+        let idf = iter
+            .next()
+            .ok_or_else(|| error(DictErrorKind::MissingIdf))?;
+        let idf = idf
+            .parse::<f64>()
+            .ok()
+            .filter(|idf| idf.is_finite())
+            .ok_or_else(|| error(DictErrorKind::InvalidIdf(idf.into())))?;
+
+        entries.push((word.into(), idf));
+    }
+
+    Ok(entries)
+}
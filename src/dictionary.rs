@@ -0,0 +1,277 @@
+use jieba_rs::Jieba;
+use lazy_static::lazy_static;
+use std::collections::BTreeMap;
+use std::ops::Bound;
+use std::sync::Arc;
+
+use crate::dict::REMOVAL_MARKER;
+use crate::tags::TagTable;
+
+/// jieba's dictionary from the vendored jieba-rs, pinned in vendor/README.md
+#[cfg(feature = "default-dict")]
+static DEFAULT_DICT: &str = include_str!("../vendor/jieba-rs/src/data/dict.txt");
+/// Without the `default-dict` feature segmenters start out empty
+#[cfg(not(feature = "default-dict"))]
+static DEFAULT_DICT: &str = "";
+
+lazy_static! {
+    /// Default dictionary, built once on first use and shared by every
+    /// segmenter until it changes its dictionary
+    pub(crate) static ref DEFAULT_DICTIONARY: Arc<Dictionary> = {
+        let mut words = DEFAULT_DICT
+            .lines()
+            .filter_map(|line| {
+                let mut iter = line.split_whitespace();
+                let word = iter.next()?;
+                let freq = iter.next().map_or(0, |freq| freq.parse().expect("invalid default dict"));
+                Some((word, freq, iter.next().unwrap_or("")))
+            })
+            .collect::<Vec<_>>();
+        words.sort_by_key(|&(word, _, _)| word);
+        Arc::new(Dictionary::from_sorted(words))
+    };
+}
+
+/// Frequency and tag of a dictionary word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Word {
+    pub freq: usize,
+    /// Id in the tag table of the dictionary
+    tag: u32,
+}
+
+/// A Jieba instance along with the words it was built from, since jieba-rs
+/// keeps its own records private
+#[derive(Clone)]
+pub(crate) struct Dictionary {
+    jieba: Jieba,
+    words: BTreeMap<String, Word>,
+    tags: TagTable,
+}
+
+impl Dictionary {
+    pub fn empty() -> Self {
+        Dictionary {
+            jieba: Jieba::empty(),
+            words: BTreeMap::new(),
+            tags: TagTable::default(),
+        }
+    }
+
+    /// Build a dictionary from words in sorted order, the first entry wins
+    /// for duplicates like [`Dictionary::add_word`] keeps the first tag
+    pub fn from_sorted<'a, W: AsRef<str> + Into<String>>(
+        words: impl IntoIterator<Item = (W, usize, &'a str)>,
+    ) -> Self {
+        let mut jieba = Jieba::empty();
+        let mut tags = TagTable::default();
+        let mut entries: Vec<(String, Word)> = Vec::new();
+        for (word, freq, tag) in words {
+            jieba.add_word(word.as_ref(), Some(freq), Some(tag));
+            match entries.last_mut() {
+                Some((last, entry)) if last.as_str() == word.as_ref() => entry.freq = freq,
+                _ => entries.push((
+                    word.into(),
+                    Word {
+                        freq,
+                        tag: tags.id(tag),
+                    },
+                )),
+            }
+        }
+
+        Dictionary {
+            jieba,
+            words: entries.into_iter().collect(),
+            tags,
+        }
+    }
+
+    /// Pair a Jieba instance with the words it holds, given in sorted order
+    /// without duplicates, so that nothing is inserted into its trie again
+    pub fn with_jieba<'a>(
+        jieba: Jieba,
+        words: impl IntoIterator<Item = (String, usize, &'a str)>,
+    ) -> Self {
+        let mut tags = TagTable::default();
+        let words = words
+            .into_iter()
+            .map(|(word, freq, tag)| {
+                let tag = tags.id(tag);
+                (word, Word { freq, tag })
+            })
+            .collect();
+        Dictionary { jieba, words, tags }
+    }
+
+    pub fn jieba(&self) -> &Jieba {
+        &self.jieba
+    }
+
+    /// All words in sorted order
+    pub fn words(&self) -> &BTreeMap<String, Word> {
+        &self.words
+    }
+
+    /// Tag of a word
+    pub fn tag(&self, word: &Word) -> &str {
+        &self.tags.names()[word.tag as usize]
+    }
+
+    /// Words starting with `prefix`, or with `fuzzy` also those starting
+    /// within one edit of it, most frequent first and at most `limit`.
+    ///
+    /// Exact matches come before fuzzy ones. A fuzzy search scans the whole
+    /// dictionary, since the edit may be on the first character. `limit`
+    /// has to be at least 1.
+    pub fn prefix_search(
+        &self,
+        prefix: &str,
+        limit: usize,
+        fuzzy: bool,
+    ) -> Result<Vec<(&str, &Word)>, String> {
+        if limit == 0 {
+            return Err("limit must be at least 1".into());
+        }
+        let mut matches = if fuzzy {
+            self.words
+                .iter()
+                .filter_map(|(word, entry)| {
+                    prefix_distance(word, prefix).map(|distance| (distance, word.as_str(), entry))
+                })
+                .collect::<Vec<_>>()
+        } else {
+            self.words
+                .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+                .take_while(|(word, _)| word.starts_with(prefix))
+                .map(|(word, entry)| (0, word.as_str(), entry))
+                .collect::<Vec<_>>()
+        };
+        let order = |(a_distance, a_word, a): &(usize, &str, &Word),
+                     (b_distance, b_word, b): &(usize, &str, &Word)| {
+            a_distance
+                .cmp(b_distance)
+                .then(b.freq.cmp(&a.freq))
+                .then(a_word.cmp(b_word))
+        };
+        if matches.len() > limit {
+            matches.select_nth_unstable_by(limit - 1, order);
+        }
+        matches.truncate(limit);
+        matches.sort_by(order);
+        Ok(matches
+            .into_iter()
+            .map(|(_, word, entry)| (word, entry))
+            .collect())
+    }
+
+    /// Sum of all word frequencies
+    pub fn total(&self) -> usize {
+        self.words.values().map(|entry| entry.freq).sum()
+    }
+
+    /// Write the dictionary in jieba's `word freq tag` format, sorted by word.
+    ///
+    /// With a `base` dictionary, only words missing from it or with another
+    /// frequency or tag are written, after `word -` lines for the words of
+    /// `base` that were removed.
+    pub fn export(&self, base: Option<&Dictionary>) -> String {
+        let mut buf = String::new();
+        if let Some(base) = base {
+            for word in base
+                .words
+                .keys()
+                .filter(|&word| !self.words.contains_key(word))
+            {
+                buf.push_str(word);
+                buf.push(' ');
+                buf.push_str(REMOVAL_MARKER);
+                buf.push('\n');
+            }
+        }
+        for (word, entry) in &self.words {
+            let tag = self.tag(entry);
+            if let Some(base) = base {
+                let old = base.words.get(word);
+                if matches!(old, Some(old) if old.freq == entry.freq && base.tag(old) == tag) {
+                    continue;
+                }
+            }
+            buf.push_str(word);
+            buf.push(' ');
+            buf.push_str(&entry.freq.to_string());
+            if !tag.is_empty() {
+                buf.push(' ');
+                buf.push_str(tag);
+            }
+            buf.push('\n');
+        }
+        buf
+    }
+
+    /// Add a word, or update the frequency of a known word while keeping its
+    /// tag like jieba-rs does, return the frequency
+    pub fn add_word(&mut self, word: &str, freq: Option<usize>, tag: Option<&str>) -> usize {
+        let freq = self.jieba.add_word(word, freq, tag);
+        match self.words.get_mut(word) {
+            Some(entry) => entry.freq = freq,
+            None => {
+                let tag = self.tags.id(tag.unwrap_or(""));
+                self.words.insert(word.into(), Word { freq, tag });
+            }
+        }
+        freq
+    }
+
+    /// Remove words, return how many were in the dictionary.
+    ///
+    /// jieba-rs cannot remove a word from its prefix structure, so the Jieba
+    /// instance is rebuilt from the remaining words, once per call.
+    pub fn remove_words<'a>(&mut self, words: impl IntoIterator<Item = &'a str>) -> usize {
+        let removed = words
+            .into_iter()
+            .filter(|&word| self.words.remove(word).is_some())
+            .count();
+        if removed > 0 {
+            self.jieba = Jieba::empty();
+            for (word, entry) in &self.words {
+                let tag = &self.tags.names()[entry.tag as usize];
+                self.jieba.add_word(word, Some(entry.freq), Some(tag));
+            }
+        }
+        removed
+    }
+}
+
+/// Edit distance between `prefix` and the closest prefix of `word`, if at
+/// most one character of `prefix` has to be replaced, deleted or inserted,
+/// or two adjacent ones swapped. The word has to cover the whole edited
+/// prefix, so words shorter than the edited prefix never match.
+fn prefix_distance(word: &str, prefix: &str) -> Option<usize> {
+    let mut word_chars = word.chars();
+    let mut prefix_chars = prefix.chars();
+    loop {
+        let (word_rest, prefix_rest) = (word_chars.as_str(), prefix_chars.as_str());
+        match (word_chars.next(), prefix_chars.next()) {
+            (_, None) => return Some(0),
+            (Some(a), Some(b)) if a == b => continue,
+            (None, Some(_)) => return None,
+            (Some(a), Some(b)) => {
+                let (word_after, prefix_after) = (word_chars.as_str(), prefix_chars.as_str());
+                let replaced = word_after.starts_with(prefix_after);
+                let deleted = word_rest.starts_with(prefix_after);
+                let inserted = word_after.starts_with(prefix_rest);
+                let mut swapped = prefix_chars.clone();
+                let transposed = swapped.next() == Some(a)
+                    && word_after
+                        .strip_prefix(b)
+                        .is_some_and(|rest| rest.starts_with(swapped.as_str()));
+                return if replaced || deleted || inserted || transposed {
+                    Some(1)
+                } else {
+                    None
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,632 @@
+//! C ABI exports for the native Deno FFI backend, see `bindings/bindings.ts`.
+//!
+//! Strings and buffers are passed as `(pointer, length)` pairs. Returned
+//! buffers hold a big-endian `u32` length followed by the bytes, like
+//! deno_bindgen does, and must be released with `free_buffer` once read.
+//! Structured values are JSON encoded, fallible calls return `{"Ok": ...}`
+//! or `{"Err": "..."}`.
+//!
+//! Every function takes a segmenter handle created by `segmenter_new` or
+//! `segmenter_empty`, or null for the default instance, except for the `token_stream_*` functions
+//! taking a stream created by `token_stream_new`, and the Chinese script
+//! conversions `to_simplified` and `to_traditional`. Callers must only pass
+//! handles that have not been freed, and pointers valid for the given
+//! lengths.
+#![allow(clippy::missing_safety_doc)]
+
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::convert;
+use crate::keywords::TextRankOptions;
+use crate::normalize::NormalizeOptions;
+use crate::offsets::OffsetUnit;
+use crate::pinyin::PinyinStyle;
+use crate::segmenter::{tokenize_mode, Segmenter};
+use crate::stream::TokenStream;
+use crate::MUTEXERROR;
+
+const SERDEERROR: &str = "SerdeError";
+const NULL_HANDLE: &str = "the default instance cannot be replaced";
+
+lazy_static! {
+    static ref JIEBA: Mutex<Segmenter> = Mutex::new(Segmenter::new());
+}
+
+type Handle = *const Mutex<Segmenter>;
+
+unsafe fn segmenter<'a>(handle: Handle) -> MutexGuard<'a, Segmenter> {
+    if handle.is_null() { &*JIEBA } else { &*handle }
+        .lock()
+        .expect(MUTEXERROR)
+}
+
+/// Token streams are locked like segmenters, since pushing to one from
+/// nonblocking calls can overlap
+type StreamHandle = *const Mutex<TokenStream>;
+
+unsafe fn stream<'a>(stream: StreamHandle) -> MutexGuard<'a, TokenStream> {
+    (*stream).lock().expect(MUTEXERROR)
+}
+
+unsafe fn bytes<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, len)
+    }
+}
+
+unsafe fn string<'a>(ptr: *const u8, len: usize) -> Cow<'a, str> {
+    String::from_utf8_lossy(bytes(ptr, len))
+}
+
+unsafe fn json<'a, T: serde::Deserialize<'a>>(ptr: *const u8, len: usize) -> Result<T, String> {
+    serde_json::from_slice(bytes(ptr, len)).map_err(|err| err.to_string())
+}
+
+/// Prefix bytes with their big-endian length and hand them to the caller
+fn to_raw(bytes: &[u8]) -> *const u8 {
+    let mut buf = Vec::with_capacity(bytes.len() + 4);
+    buf.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    buf.extend_from_slice(bytes);
+    Box::into_raw(buf.into_boxed_slice()) as *const u8
+}
+
+fn to_buffer<T: Serialize + ?Sized>(value: &T) -> *const u8 {
+    to_raw(&serde_json::to_vec(value).expect(SERDEERROR))
+}
+
+fn to_raw_buffer(values: &[u32]) -> *const u8 {
+    to_raw(
+        &values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn to_result<E: ToString>(result: Result<impl Serialize, E>) -> *const u8 {
+    to_buffer(&result.map_err(|err| err.to_string()))
+}
+
+fn into_handle(segmenter: Segmenter) -> Handle {
+    Box::into_raw(Box::new(Mutex::new(segmenter)))
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn free_buffer(ptr: *mut u8) {
+    if ptr.is_null() {
+        return;
+    }
+    let mut len = [0; 4];
+    len.copy_from_slice(std::slice::from_raw_parts(ptr, 4));
+    let len = u32::from_be_bytes(len) as usize + 4;
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)));
+}
+
+#[no_mangle]
+pub extern "C" fn segmenter_new() -> Handle {
+    into_handle(Segmenter::new())
+}
+
+/// Create a segmenter with an empty dictionary
+#[no_mangle]
+pub extern "C" fn segmenter_empty() -> Handle {
+    into_handle(Segmenter::empty())
+}
+
+/// Give a segmenter from `segmenter_empty` a dictionary in `word freq tag`
+/// format, as if it was created with it. The default instance cannot be
+/// replaced.
+#[no_mangle]
+pub unsafe extern "C" fn segmenter_with_dict(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+) -> *const u8 {
+    if handle.is_null() {
+        return to_result(Err::<(), _>(NULL_HANDLE));
+    }
+    to_result(Segmenter::with_dict(bytes(ptr, len)).map(|created| *segmenter(handle) = created))
+}
+
+/// Give a segmenter from `segmenter_empty` a dictionary snapshot, as if it
+/// was created from it. The default instance cannot be replaced.
+#[no_mangle]
+pub unsafe extern "C" fn segmenter_from_snapshot(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+) -> *const u8 {
+    if handle.is_null() {
+        return to_result(Err::<(), _>(NULL_HANDLE));
+    }
+    to_result(Segmenter::from_snapshot(bytes(ptr, len)).map(|created| *segmenter(handle) = created))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn segmenter_free(handle: Handle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle as *mut Mutex<Segmenter>));
+    }
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn load_dict(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_result(segmenter(handle).load_dict(bytes(ptr, len)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn add_word(
+    handle: Handle,
+    word_ptr: *const u8,
+    word_len: usize,
+    freq: i32,
+    tag_ptr: *const u8,
+    tag_len: usize,
+) -> usize {
+    let tag = string(tag_ptr, tag_len);
+    segmenter(handle).add_word(
+        &string(word_ptr, word_len),
+        if freq < 0 { None } else { Some(freq as usize) },
+        if tag.is_empty() { None } else { Some(&tag) },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn remove_word(handle: Handle, ptr: *const u8, len: usize) -> u8 {
+    segmenter(handle).remove_word(&string(ptr, len)) as u8
+}
+
+/// `ptr` holds a JSON array of words
+#[no_mangle]
+pub unsafe extern "C" fn remove_words(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_result(json::<Vec<String>>(ptr, len).map(|words| segmenter(handle).remove_words(&words)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn suggest_freq(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    tune: u8,
+) -> usize {
+    segmenter(handle).suggest_freq(&string(ptr, len), tune != 0)
+}
+
+/// `ptr` holds a JSON array of pieces
+#[no_mangle]
+pub unsafe extern "C" fn suggest_split_freq(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    tune: u8,
+) -> *const u8 {
+    to_result(
+        json::<Vec<String>>(ptr, len)
+            .map(|pieces| segmenter(handle).suggest_split_freq(&pieces, tune != 0)),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn reset(handle: Handle) {
+    segmenter(handle).reset()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn lookup(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_buffer(&segmenter(handle).lookup(&string(ptr, len)))
+}
+
+/// `ptr` holds a JSON array of words
+#[no_mangle]
+pub unsafe extern "C" fn lookup_batch(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    let segmenter = segmenter(handle);
+    to_result(json::<Vec<String>>(ptr, len).map(|words| segmenter.lookup_batch(&words)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn prefix_search(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    limit: usize,
+    fuzzy: u8,
+) -> *const u8 {
+    to_result(segmenter(handle).prefix_search(&string(ptr, len), limit, fuzzy == 1))
+}
+
+/// The buffer holds the dictionary text in `word freq tag` format
+#[no_mangle]
+pub unsafe extern "C" fn export_dict(handle: Handle, delta: u8) -> *const u8 {
+    to_raw(segmenter(handle).export_dict(delta == 1).as_bytes())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn snapshot(handle: Handle) -> *const u8 {
+    to_raw(&segmenter(handle).snapshot())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn load_snapshot(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_result(segmenter(handle).load_snapshot(bytes(ptr, len)))
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn begin(handle: Handle) -> usize {
+    segmenter(handle).begin()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn commit(handle: Handle) -> *const u8 {
+    to_result(segmenter(handle).commit())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rollback(handle: Handle) -> *const u8 {
+    to_result(segmenter(handle).rollback())
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn cut(handle: Handle, ptr: *const u8, len: usize, hmm: u8) -> *const u8 {
+    to_buffer(&segmenter(handle).cut(&string(ptr, len), hmm == 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cut_all(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_buffer(&segmenter(handle).cut_all(&string(ptr, len)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cut_for_search(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    hmm: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).cut_for_search(&string(ptr, len), hmm == 1))
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn tag(handle: Handle, ptr: *const u8, len: usize, hmm: u8) -> *const u8 {
+    to_buffer(&segmenter(handle).tag(&string(ptr, len), hmm == 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tokenize(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).tokenize(
+        &string(ptr, len),
+        tokenize_mode(mode),
+        hmm == 1,
+        OffsetUnit::from(unit),
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn split_sentences(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    unit: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).split_sentences(&string(ptr, len), OffsetUnit::from(unit)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tokenize_sentences(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).tokenize_sentences(
+        &string(ptr, len),
+        tokenize_mode(mode),
+        hmm == 1,
+        OffsetUnit::from(unit),
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tokenize_with_tags(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).tokenize_with_tags(
+        &string(ptr, len),
+        tokenize_mode(mode),
+        hmm == 1,
+        OffsetUnit::from(unit),
+    ))
+}
+
+/// The buffer holds little-endian `u32`s: the token count, the `start, end`
+/// pairs and, if `with_tags` is set, the tag ids
+#[no_mangle]
+pub unsafe extern "C" fn tokenize_binary(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+    with_tags: u8,
+) -> *const u8 {
+    let buffer = segmenter(handle).tokenize_binary(
+        &string(ptr, len),
+        tokenize_mode(mode),
+        hmm == 1,
+        OffsetUnit::from(unit),
+        with_tags == 1,
+    );
+    let mut values = Vec::with_capacity(1 + buffer.offsets.len() + buffer.tags.len());
+    values.push(buffer.offsets.len() as u32 / 2);
+    values.extend(buffer.offsets);
+    values.extend(buffer.tags);
+    to_raw_buffer(&values)
+}
+
+/// Start tokenizing a text pushed in chunks, free the stream with
+/// `token_stream_free`
+#[no_mangle]
+pub unsafe extern "C" fn token_stream_new(
+    handle: Handle,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+) -> StreamHandle {
+    let stream =
+        segmenter(handle).token_stream(tokenize_mode(mode), hmm == 1, OffsetUnit::from(unit));
+    Box::into_raw(Box::new(Mutex::new(stream)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn token_stream_push(
+    handle: StreamHandle,
+    ptr: *const u8,
+    len: usize,
+) -> *const u8 {
+    to_buffer(&stream(handle).push(&string(ptr, len)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn token_stream_finish(handle: StreamHandle) -> *const u8 {
+    to_buffer(&stream(handle).finish())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn token_stream_free(handle: StreamHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle as *mut Mutex<TokenStream>));
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tag_names(handle: Handle) -> *const u8 {
+    to_buffer(segmenter(handle).tag_names())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tokenize_batch(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    lengths_ptr: *const u32,
+    lengths_len: usize,
+    mode: u8,
+    hmm: u8,
+) -> *const u8 {
+    let lengths = if lengths_len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(lengths_ptr, lengths_len)
+    };
+    to_result(segmenter(handle).tokenize_batch(
+        &string(ptr, len),
+        lengths,
+        tokenize_mode(mode),
+        hmm == 1,
+    ))
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn pinyin(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    style: u8,
+    hmm: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).pinyin(&string(ptr, len), PinyinStyle::from(style), hmm == 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tokenize_with_pinyin(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+    style: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).tokenize_with_pinyin(
+        &string(ptr, len),
+        tokenize_mode(mode),
+        hmm == 1,
+        OffsetUnit::from(unit),
+        PinyinStyle::from(style),
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pinyin_initials(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    hmm: u8,
+) -> *const u8 {
+    to_buffer(&segmenter(handle).pinyin_initials(&string(ptr, len), hmm == 1))
+}
+
+// =======================================================
+
+#[no_mangle]
+pub unsafe extern "C" fn add_stop_word(handle: Handle, ptr: *const u8, len: usize) -> u8 {
+    segmenter(handle).add_stop_word(&string(ptr, len)) as u8
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn remove_stop_word(handle: Handle, ptr: *const u8, len: usize) -> u8 {
+    segmenter(handle).remove_stop_word(&string(ptr, len)) as u8
+}
+
+/// `ptr` holds a JSON array of words
+#[no_mangle]
+pub unsafe extern "C" fn set_stop_words(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_result(
+        json::<BTreeSet<String>>(ptr, len)
+            .map(|stop_words| segmenter(handle).set_stop_words(stop_words)),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn load_stop_words(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_result(segmenter(handle).load_stop_words(bytes(ptr, len)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn get_stop_words(handle: Handle) -> *const u8 {
+    to_buffer(segmenter(handle).stop_words())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn load_idf(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_result(segmenter(handle).load_idf(bytes(ptr, len)))
+}
+
+/// `allowed_pos_ptr` holds a JSON array of tags
+#[no_mangle]
+pub unsafe extern "C" fn extract_tags_by_tfidf(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    top_k: usize,
+    allowed_pos_ptr: *const u8,
+    allowed_pos_len: usize,
+) -> *const u8 {
+    to_result(
+        json::<Vec<String>>(allowed_pos_ptr, allowed_pos_len).map(|allowed_pos| {
+            segmenter(handle).extract_tags_by_tfidf(&string(ptr, len), top_k, &allowed_pos)
+        }),
+    )
+}
+
+/// `ptr` holds a JSON object of TextRank parameters
+#[no_mangle]
+pub unsafe extern "C" fn set_textrank_options(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+) -> *const u8 {
+    to_result(
+        json::<TextRankOptions>(ptr, len)
+            .and_then(|options| segmenter(handle).set_textrank_options(options)),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn get_textrank_options(handle: Handle) -> *const u8 {
+    to_buffer(segmenter(handle).textrank_options())
+}
+
+/// `allowed_pos_ptr` holds a JSON array of tags
+#[no_mangle]
+pub unsafe extern "C" fn extract_tags_by_textrank(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+    top_k: usize,
+    allowed_pos_ptr: *const u8,
+    allowed_pos_len: usize,
+) -> *const u8 {
+    to_result(
+        json::<Vec<String>>(allowed_pos_ptr, allowed_pos_len).map(|allowed_pos| {
+            segmenter(handle).extract_tags_by_textrank(&string(ptr, len), top_k, &allowed_pos)
+        }),
+    )
+}
+
+// =======================================================
+
+/// `ptr` holds a JSON object of normalization steps
+#[no_mangle]
+pub unsafe extern "C" fn set_normalize_options(
+    handle: Handle,
+    ptr: *const u8,
+    len: usize,
+) -> *const u8 {
+    to_result(
+        json::<NormalizeOptions>(ptr, len)
+            .map(|options| segmenter(handle).set_normalize_options(options)),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn get_normalize_options(handle: Handle) -> *const u8 {
+    to_buffer(segmenter(handle).normalize_options())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn normalize(handle: Handle, ptr: *const u8, len: usize) -> *const u8 {
+    to_buffer(&segmenter(handle).normalize(&string(ptr, len)).text)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn set_traditional(handle: Handle, traditional: u8) {
+    segmenter(handle).set_traditional(traditional == 1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn is_traditional(handle: Handle) -> u8 {
+    segmenter(handle).traditional() as u8
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn to_simplified(ptr: *const u8, len: usize) -> *const u8 {
+    to_buffer(&convert::to_simplified(&string(ptr, len)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn to_traditional(ptr: *const u8, len: usize) -> *const u8 {
+    to_buffer(&convert::to_traditional(&string(ptr, len)))
+}
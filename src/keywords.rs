@@ -0,0 +1,239 @@
+use jieba_rs::{Jieba, KeywordExtract, TextRank, TFIDF};
+use self_cell::self_cell;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use crate::dictionary::Dictionary;
+use crate::types::Keyword;
+
+/// Stop words used by jieba-rs keyword extractors out of the box
+const DEFAULT_STOP_WORDS: &[&str] = &[
+    "the", "of", "is", "and", "to", "in", "that", "we", "for", "an", "are", "by", "be", "as", "on",
+    "with", "can", "if", "from", "which", "you", "it", "this", "then", "at", "have", "all", "not",
+    "one", "has", "or",
+];
+
+pub(crate) fn default_stop_words() -> BTreeSet<String> {
+    DEFAULT_STOP_WORDS.iter().map(|&word| word.into()).collect()
+}
+
+self_cell!(
+    /// The TF-IDF extractor of jieba-rs along with the dictionary it cuts
+    /// sentences with
+    pub(crate) struct TfidfExtractor {
+        owner: Arc<Dictionary>,
+        #[covariant]
+        dependent: TFIDF,
+    }
+);
+
+impl TfidfExtractor {
+    /// Build an extractor with jieba's IDF table when the `default-idf`
+    /// feature embeds it, then the given tables in `word idf` format loaded
+    /// over it in order
+    pub fn build(
+        dict: Arc<Dictionary>,
+        idf_tables: &[String],
+        stop_words: &BTreeSet<String>,
+    ) -> Self {
+        TfidfExtractor::new(dict, |dict| {
+            let mut tfidf = TFIDF::new_with_jieba(dict.jieba());
+            for table in idf_tables {
+                tfidf
+                    .load_dict(&mut table.as_bytes())
+                    .expect("invalid idf table");
+            }
+            tfidf.set_stop_words(stop_words.clone());
+            tfidf
+        })
+    }
+
+    pub fn set_stop_words(&mut self, stop_words: &BTreeSet<String>) {
+        self.with_dependent_mut(|_, tfidf| tfidf.set_stop_words(stop_words.clone()));
+    }
+
+    /// Extract the `top_k` keywords of a sentence
+    pub fn extract_tags(
+        &self,
+        sentence: &str,
+        top_k: usize,
+        allowed_pos: &[String],
+    ) -> Vec<Keyword> {
+        self.borrow_dependent()
+            .extract_tags(sentence, top_k, allowed_pos.to_vec())
+            .into_iter()
+            .map(Keyword::from)
+            .collect()
+    }
+}
+
+self_cell!(
+    /// The TextRank extractor of jieba-rs along with the dictionary it cuts
+    /// sentences with
+    pub(crate) struct TextRankExtractor {
+        owner: Arc<Dictionary>,
+        #[covariant]
+        dependent: TextRank,
+    }
+);
+
+impl TextRankExtractor {
+    pub fn build(dict: Arc<Dictionary>, stop_words: &BTreeSet<String>) -> Self {
+        TextRankExtractor::new(dict, |dict| {
+            let mut textrank = TextRank::new_with_jieba(dict.jieba());
+            textrank.set_stop_words(stop_words.clone());
+            textrank
+        })
+    }
+
+    pub fn set_stop_words(&mut self, stop_words: &BTreeSet<String>) {
+        self.with_dependent_mut(|_, textrank| textrank.set_stop_words(stop_words.clone()));
+    }
+
+    /// Extract the `top_k` keywords of a sentence with the default
+    /// parameters
+    pub fn extract_tags(
+        &self,
+        sentence: &str,
+        top_k: usize,
+        allowed_pos: &[String],
+    ) -> Vec<Keyword> {
+        self.borrow_dependent()
+            .extract_tags(sentence, top_k, allowed_pos.to_vec())
+            .into_iter()
+            .map(Keyword::from)
+            .collect()
+    }
+}
+
+#[inline]
+fn is_candidate(word: &str, stop_words: &BTreeSet<String>) -> bool {
+    word.chars().count() >= 2 && !stop_words.contains(&word.to_lowercase())
+}
+
+/// Parameters of TextRank keyword extraction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct TextRankOptions {
+    /// Size of the co-occurrence window, in words
+    pub span: usize,
+    /// Probability of following a co-occurrence edge
+    pub damping_factor: f64,
+    /// Stop iterating once no rank changes by more than this
+    pub tolerance: f64,
+    /// Upper bound on the number of iterations
+    pub max_iterations: usize,
+}
+
+impl Default for TextRankOptions {
+    fn default() -> Self {
+        TextRankOptions {
+            span: 5,
+            damping_factor: 0.85,
+            tolerance: 0.0,
+            max_iterations: 20,
+        }
+    }
+}
+
+impl TextRankOptions {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.span < 2 {
+            return Err(format!("span {} must be at least 2", self.span));
+        }
+        if !(self.damping_factor > 0.0 && self.damping_factor < 1.0) {
+            return Err(format!(
+                "damping factor {} must be between 0 and 1",
+                self.damping_factor
+            ));
+        }
+        if self.tolerance < 0.0 {
+            return Err(format!("tolerance {} must not be negative", self.tolerance));
+        }
+        if self.max_iterations == 0 {
+            return Err("max iterations must be at least 1".into());
+        }
+        Ok(())
+    }
+}
+
+/// TextRank with other parameters than the ones jieba-rs fixes, ranked the
+/// same way
+pub(crate) fn extract_tags_by_tuned_textrank(
+    jieba: &Jieba,
+    stop_words: &BTreeSet<String>,
+    options: &TextRankOptions,
+    sentence: &str,
+    top_k: usize,
+    allowed_pos: &[String],
+) -> Vec<Keyword> {
+    let tags = jieba.tag(sentence, true);
+    let is_allowed = |tag: &str| allowed_pos.is_empty() || allowed_pos.iter().any(|pos| pos == tag);
+
+    let mut word_ids: HashMap<&str, usize> = HashMap::new();
+    let mut words = Vec::new();
+    for tag in tags.iter().filter(|tag| is_allowed(tag.tag)) {
+        word_ids.entry(tag.word).or_insert_with(|| {
+            words.push(tag.word);
+            words.len() - 1
+        });
+    }
+
+    let mut cooccurrence: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for (i, tag) in tags.iter().enumerate() {
+        if !is_allowed(tag.tag) || !is_candidate(tag.word, stop_words) {
+            continue;
+        }
+        for other in tags.iter().take(i + options.span).skip(i + 1) {
+            if !is_allowed(other.tag) || !is_candidate(other.word, stop_words) {
+                continue;
+            }
+            *cooccurrence
+                .entry((word_ids[tag.word], word_ids[other.word]))
+                .or_insert(0) += 1;
+        }
+    }
+
+    let mut graph: Vec<Vec<(usize, f64)>> = vec![Vec::new(); words.len()];
+    for (&(u, v), &weight) in &cooccurrence {
+        graph[u].push((v, weight as f64));
+        graph[v].push((u, weight as f64));
+    }
+
+    let outflow = graph
+        .iter()
+        .map(|edges| edges.iter().map(|&(_, weight)| weight).sum::<f64>())
+        .collect::<Vec<_>>();
+    let mut ranks = vec![1.0 / words.len() as f64; words.len()];
+    for _ in 0..options.max_iterations {
+        let mut delta: f64 = 0.0;
+        for (i, edges) in graph.iter().enumerate() {
+            let sum = edges
+                .iter()
+                .map(|&(j, weight)| weight / outflow[j] * ranks[j])
+                .sum::<f64>();
+            let rank = (1.0 - options.damping_factor) + options.damping_factor * sum;
+            delta = delta.max((rank - ranks[i]).abs());
+            ranks[i] = rank;
+        }
+        if delta <= options.tolerance {
+            break;
+        }
+    }
+
+    let mut keywords = ranks
+        .into_iter()
+        .map(|rank| rank * 1e10)
+        .enumerate()
+        .collect::<Vec<_>>();
+    keywords.sort_by(|(a_id, a), (b_id, b)| b.total_cmp(a).then_with(|| a_id.cmp(b_id)));
+    keywords
+        .into_iter()
+        .take(top_k)
+        .map(|(id, weight)| Keyword {
+            keyword: words[id].into(),
+            weight,
+        })
+        .collect()
+}
@@ -0,0 +1,285 @@
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::convert::to_simplified;
+
+/// NFKC form of every character it changes, generated by `scripts/nfkc.js`
+static NFKC: &str = include_str!("data/nfkc.txt");
+/// Pairs joined by canonical composition, generated by `scripts/nfkc.js`
+static COMPOSE: &str = include_str!("data/compose.txt");
+
+lazy_static! {
+    static ref NFKC_TABLE: HashMap<char, Vec<char>> = NFKC
+        .lines()
+        .map(|line| {
+            let mut chars = parse_chars(line);
+            let c = chars.remove(0);
+            (c, chars)
+        })
+        .collect();
+    static ref COMPOSE_TABLE: HashMap<(char, char), char> = COMPOSE
+        .lines()
+        .map(|line| match parse_chars(line)[..] {
+            [first, second, composed] => ((first, second), composed),
+            _ => panic!("invalid composition table"),
+        })
+        .collect();
+}
+
+/// Characters written as space separated hexadecimal code points
+fn parse_chars(line: &str) -> Vec<char> {
+    line.split(' ')
+        .map(|code| {
+            u32::from_str_radix(code, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .expect("invalid normalization table")
+        })
+        .collect()
+}
+
+/// Hangul syllables are composed by arithmetic rather than by table
+fn compose_hangul(first: char, second: char) -> Option<char> {
+    const S_BASE: u32 = 0xAC00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11A7;
+    const T_COUNT: u32 = 28;
+    const N_COUNT: u32 = 21 * T_COUNT;
+
+    let (first, second) = (first as u32, second as u32);
+    if (L_BASE..L_BASE + 19).contains(&first) && (V_BASE..V_BASE + 21).contains(&second) {
+        char::from_u32(S_BASE + (first - L_BASE) * N_COUNT + (second - V_BASE) * T_COUNT)
+    } else if (S_BASE..S_BASE + 19 * N_COUNT).contains(&first)
+        && (first - S_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&second)
+    {
+        char::from_u32(first + second - T_BASE)
+    } else {
+        None
+    }
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    COMPOSE_TABLE
+        .get(&(first, second))
+        .copied()
+        .or_else(|| compose_hangul(first, second))
+}
+
+/// Zero-width characters, invisible and never part of a word
+#[inline]
+pub(crate) fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{feff}' | '\u{200b}'..='\u{200d}' | '\u{2060}')
+}
+
+/// Full-width ASCII forms and the ideographic space to their ASCII
+/// counterparts
+#[inline]
+fn to_half_width(c: char) -> char {
+    match c {
+        '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        _ => c,
+    }
+}
+
+/// Steps of the normalization run before segmentation, all off by default
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct NormalizeOptions {
+    /// Unicode NFKC, combining marks are not reordered
+    pub nfkc: bool,
+    /// Full-width ASCII forms and the ideographic space to ASCII
+    pub full_width: bool,
+    /// Case folding by lowercasing
+    pub case_fold: bool,
+    /// Remove zero-width spaces, joiners and byte order marks
+    pub zero_width: bool,
+}
+
+impl NormalizeOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.nfkc || self.full_width || self.case_fold || self.zero_width
+    }
+}
+
+/// A normalized text along with the span of the source text every one of
+/// its characters comes from
+pub(crate) struct Normalized<'a> {
+    pub text: Cow<'a, str>,
+    /// Char span in the source of every char of `text`, unless they are the
+    /// chars of the source
+    spans: Option<Vec<(usize, usize)>>,
+    /// Length of the source in chars, along with `spans`
+    source_len: usize,
+    /// The text before its conversion to Simplified Chinese, which words are
+    /// taken from
+    original: Option<Original<'a>>,
+}
+
+/// A text converted character for character
+struct Original<'a> {
+    text: Cow<'a, str>,
+    /// Byte position of every char boundary, in the converted text and in the
+    /// original one
+    bounds: Vec<(usize, usize)>,
+}
+
+impl<'a> Original<'a> {
+    /// The original of the bytes `start..end` of the converted text
+    fn slice(&self, start: usize, end: usize) -> Cow<'a, str> {
+        let position = |byte: usize| {
+            let index = self
+                .bounds
+                .binary_search_by_key(&byte, |bound| bound.0)
+                .expect("not a char boundary of the converted text");
+            self.bounds[index].1
+        };
+        let range = position(start)..position(end);
+        match self.text {
+            Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+            Cow::Owned(ref text) => Cow::Owned(text[range].to_string()),
+        }
+    }
+}
+
+impl<'a> Normalized<'a> {
+    /// Char span in the source of the char span `start..end` of the text
+    pub fn span(&self, start: usize, end: usize) -> (usize, usize) {
+        match self.spans {
+            None => (start, end),
+            Some(ref spans) if end > start => (spans[start].0, spans[end - 1].1),
+            Some(ref spans) => {
+                let position = spans.get(start).map_or(self.source_len, |span| span.0);
+                (position, position)
+            }
+        }
+    }
+
+    /// The word as it was before the conversion to Simplified Chinese, given
+    /// a slice of the text
+    pub fn original(&self, word: &str) -> Option<Cow<'a, str>> {
+        self.original.as_ref().map(|original| {
+            let start = word.as_ptr() as usize - self.text.as_ptr() as usize;
+            original.slice(start, start + word.len())
+        })
+    }
+
+    /// Words cut from the text, borrowed from the source unless normalized,
+    /// and as they were before any conversion
+    pub fn words(&self, cut: impl Fn(&str) -> Vec<&str>) -> Vec<Cow<'a, str>> {
+        match self.text {
+            Cow::Borrowed(text) => cut(text).into_iter().map(Cow::Borrowed).collect(),
+            Cow::Owned(ref text) => cut(text)
+                .into_iter()
+                .map(|word| {
+                    self.original(word)
+                        .unwrap_or_else(|| Cow::Owned(word.to_string()))
+                })
+                .collect(),
+        }
+    }
+
+    /// Convert the text from Traditional to Simplified Chinese, keeping the
+    /// text before as the original of words
+    pub fn into_simplified(self) -> Self {
+        let converted = to_simplified(&self.text);
+        if converted == self.text {
+            return self;
+        }
+
+        let bounds = |text: &str| {
+            text.char_indices()
+                .map(|(index, _)| index)
+                .chain(std::iter::once(text.len()))
+                .collect::<Vec<_>>()
+        };
+        let bounds = bounds(&converted)
+            .into_iter()
+            .zip(bounds(&self.text))
+            .collect();
+        Normalized {
+            text: Cow::Owned(converted),
+            spans: self.spans,
+            source_len: self.source_len,
+            original: Some(Original {
+                text: self.text,
+                bounds,
+            }),
+        }
+    }
+}
+
+/// Normalize a text, keeping track of where every character comes from.
+///
+/// Characters expanded into several, like `㈱` into `(株)`, all map to the
+/// source character, and characters composed into one, like `e` and a
+/// combining acute accent into `é`, map to all of them.
+pub(crate) fn normalize<'a>(source: &'a str, options: &NormalizeOptions) -> Normalized<'a> {
+    if !options.is_enabled() {
+        return Normalized {
+            text: Cow::Borrowed(source),
+            spans: None,
+            source_len: 0,
+            original: None,
+        };
+    }
+
+    let mut chars: Vec<char> = Vec::with_capacity(source.len());
+    let mut spans: Vec<(usize, usize)> = Vec::with_capacity(source.len());
+    let mut source_len = 0;
+
+    for (position, c) in source.chars().enumerate() {
+        source_len += 1;
+        for c in normalize_char(c, options) {
+            let composed = match chars.last() {
+                Some(&last) if options.nfkc => compose(last, c),
+                _ => None,
+            };
+            match composed {
+                Some(composed) => {
+                    *chars.last_mut().unwrap() = composed;
+                    spans.last_mut().unwrap().1 = position + 1;
+                }
+                None => {
+                    chars.push(c);
+                    spans.push((position, position + 1));
+                }
+            }
+        }
+    }
+    Normalized {
+        text: Cow::Owned(chars.into_iter().collect()),
+        spans: Some(spans),
+        source_len,
+        original: None,
+    }
+}
+
+/// Characters a single character normalizes to, before composition with the
+/// characters around it
+pub(crate) fn normalize_char(c: char, options: &NormalizeOptions) -> Vec<char> {
+    if options.zero_width && is_zero_width(c) {
+        return Vec::new();
+    }
+
+    let mut mapped = match NFKC_TABLE.get(&c) {
+        Some(nfkc) if options.nfkc => nfkc.clone(),
+        _ => vec![c],
+    };
+    if options.full_width {
+        mapped.iter_mut().for_each(|c| *c = to_half_width(*c));
+    }
+    if options.case_fold {
+        mapped = mapped.iter().flat_map(|c| c.to_lowercase()).collect();
+    }
+    mapped
+}
+
+/// Whether normalization composes two characters into one
+pub(crate) fn composes(first: char, second: char, options: &NormalizeOptions) -> bool {
+    options.nfkc && compose(first, second).is_some()
+}
@@ -0,0 +1,54 @@
+/// Unit of the `start` / `end` positions reported for tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetUnit {
+    /// Unicode scalar values, as jieba reports them
+    Char,
+    /// UTF-8 bytes, as Rust and `TextEncoder` index
+    Utf8,
+    /// UTF-16 code units, as JavaScript strings index
+    Utf16,
+}
+
+impl From<u8> for OffsetUnit {
+    fn from(unit: u8) -> Self {
+        match unit {
+            1 => OffsetUnit::Utf8,
+            2 => OffsetUnit::Utf16,
+            _ => OffsetUnit::Char,
+        }
+    }
+}
+
+/// Map from char positions of a sentence to positions in another unit
+pub(crate) struct Offsets {
+    table: Option<Vec<usize>>,
+}
+
+impl Offsets {
+    pub fn new(sentence: &str, unit: OffsetUnit) -> Self {
+        let width = match unit {
+            OffsetUnit::Char => return Offsets { table: None },
+            OffsetUnit::Utf8 => char::len_utf8,
+            OffsetUnit::Utf16 => char::len_utf16,
+        };
+
+        let mut table = Vec::with_capacity(sentence.len() + 1);
+        let mut offset = 0;
+        table.push(offset);
+        for c in sentence.chars() {
+            offset += width(c);
+            table.push(offset);
+        }
+
+        Offsets { table: Some(table) }
+    }
+
+    /// Convert a char position into the target unit
+    #[inline]
+    pub fn get(&self, position: usize) -> usize {
+        match &self.table {
+            Some(table) => table[position],
+            None => position,
+        }
+    }
+}
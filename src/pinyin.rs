@@ -0,0 +1,195 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// Characters by reading, generated by `scripts/pinyin.js`
+static PINYIN: &str = include_str!("data/pinyin.txt");
+/// Words read differently than character for character, generated by
+/// `scripts/pinyin.js`
+static PINYIN_WORDS: &str = include_str!("data/pinyin_words.txt");
+
+lazy_static! {
+    static ref CHAR_TABLE: HashMap<char, &'static str> = PINYIN
+        .lines()
+        .flat_map(|line| {
+            let (reading, chars) = line.split_once('\t').expect("invalid pinyin table");
+            chars.chars().map(move |c| (c, reading))
+        })
+        .collect();
+    static ref WORD_TABLE: WordTable = WordTable::parse(PINYIN_WORDS);
+}
+
+/// Readings of words, all with tone numbers
+struct WordTable {
+    entries: HashMap<&'static str, Vec<&'static str>>,
+    /// Length of the longest word, in chars
+    longest: usize,
+}
+
+impl WordTable {
+    fn parse(table: &'static str) -> Self {
+        let entries: HashMap<_, _> = table
+            .lines()
+            .map(|line| {
+                let (word, readings) = line.split_once('\t').expect("invalid pinyin table");
+                (word, readings.split(' ').collect::<Vec<_>>())
+            })
+            .collect();
+        let longest = entries
+            .keys()
+            .map(|word| word.chars().count())
+            .max()
+            .unwrap_or(1);
+        WordTable { entries, longest }
+    }
+}
+
+/// How syllables are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PinyinStyle {
+    /// Tone marks, as in `háng`
+    Tone,
+    /// Tone numbers, none for the neutral tone, as in `hang2`
+    ToneNumber,
+    /// No tone, as in `hang`
+    Plain,
+    /// The first letter, as in `h`
+    FirstLetter,
+}
+
+impl From<u8> for PinyinStyle {
+    fn from(style: u8) -> Self {
+        match style {
+            1 => PinyinStyle::ToneNumber,
+            2 => PinyinStyle::Plain,
+            3 => PinyinStyle::FirstLetter,
+            _ => PinyinStyle::Tone,
+        }
+    }
+}
+
+/// A part of a word, a syllable or a run of characters without a reading
+enum Syllable<'a> {
+    Reading(&'static str),
+    Text(&'a str),
+}
+
+/// Syllables of a word, read as a whole when it is in the word table, or
+/// else by the longest words of the table it contains and then character
+/// for character. Single characters of the table are only read that way as
+/// words of their own, like the particle `地`.
+fn syllables(word: &str) -> Vec<Syllable<'_>> {
+    if let Some(readings) = WORD_TABLE.entries.get(word) {
+        return readings
+            .iter()
+            .map(|&reading| Syllable::Reading(reading))
+            .collect();
+    }
+
+    let bounds = word
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(word.len()))
+        .collect::<Vec<_>>();
+    let reading = |i: usize| {
+        let c = word[bounds[i]..].chars().next().unwrap();
+        CHAR_TABLE.get(&c).copied()
+    };
+    let mut syllables = Vec::with_capacity(bounds.len() - 1);
+    let mut i = 0;
+
+    while i + 1 < bounds.len() {
+        let longest = WORD_TABLE.longest.min(bounds.len() - 1 - i);
+        let found = (2..=longest).rev().find_map(|n| {
+            let part = &word[bounds[i]..bounds[i + n]];
+            WORD_TABLE.entries.get(part).map(|readings| (n, readings))
+        });
+        if let Some((n, readings)) = found {
+            syllables.extend(readings.iter().map(|&reading| Syllable::Reading(reading)));
+            i += n;
+        } else if let Some(reading) = reading(i) {
+            syllables.push(Syllable::Reading(reading));
+            i += 1;
+        } else {
+            let start = i;
+            while i + 1 < bounds.len() && reading(i).is_none() {
+                i += 1;
+            }
+            syllables.push(Syllable::Text(&word[bounds[start]..bounds[i]]));
+        }
+    }
+    syllables
+}
+
+/// Pinyin of a word in Simplified Chinese, one syllable per character and
+/// runs of other characters as they are. Tones are the ones of the
+/// dictionary, without tone sandhi.
+pub(crate) fn pinyin(word: &str, style: PinyinStyle) -> Vec<String> {
+    syllables(word)
+        .into_iter()
+        .map(|syllable| match syllable {
+            Syllable::Reading(reading) => write_syllable(reading, style),
+            Syllable::Text(text) => text.to_string(),
+        })
+        .collect()
+}
+
+/// Append the first letter of every syllable of a word to an abbreviation,
+/// along with the letters and digits of other characters
+pub(crate) fn push_initials(word: &str, abbreviation: &mut String) {
+    for syllable in syllables(word) {
+        match syllable {
+            Syllable::Reading(reading) => abbreviation.extend(reading.chars().next()),
+            Syllable::Text(text) => {
+                abbreviation.extend(text.chars().filter(|c| c.is_alphanumeric()))
+            }
+        }
+    }
+}
+
+fn write_syllable(reading: &str, style: PinyinStyle) -> String {
+    let toneless = reading.trim_end_matches(|c: char| c.is_ascii_digit());
+    match style {
+        PinyinStyle::Tone => match reading[toneless.len()..].parse::<usize>() {
+            Ok(tone @ 1..=4) => mark_tone(toneless, tone),
+            _ => toneless.to_string(),
+        },
+        PinyinStyle::ToneNumber => reading.to_string(),
+        PinyinStyle::Plain => toneless.to_string(),
+        PinyinStyle::FirstLetter => toneless.chars().take(1).collect(),
+    }
+}
+
+/// Vowels with their marks for tones 1 to 4
+const TONE_MARKS: [(char, [char; 4]); 6] = [
+    ('a', ['ā', 'á', 'ǎ', 'à']),
+    ('e', ['ē', 'é', 'ě', 'è']),
+    ('i', ['ī', 'í', 'ǐ', 'ì']),
+    ('o', ['ō', 'ó', 'ǒ', 'ò']),
+    ('u', ['ū', 'ú', 'ǔ', 'ù']),
+    ('ü', ['ǖ', 'ǘ', 'ǚ', 'ǜ']),
+];
+
+/// Combining marks for tones 1 to 4, for syllables without a vowel like `ń`
+const COMBINING_MARKS: [char; 4] = ['\u{304}', '\u{301}', '\u{30c}', '\u{300}'];
+
+/// Write the tone mark on `a` or `e`, on the `o` of `ou`, or else on the
+/// last vowel
+fn mark_tone(syllable: &str, tone: usize) -> String {
+    let is_vowel = |c: char| TONE_MARKS.iter().any(|&(vowel, _)| vowel == c);
+    let position = syllable
+        .find(['a', 'e'])
+        .or_else(|| syllable.find("ou"))
+        .or_else(|| syllable.rfind(is_vowel));
+
+    let mut marked = String::with_capacity(syllable.len() + 2);
+    for (index, c) in syllable.char_indices() {
+        match TONE_MARKS.iter().find(|&&(vowel, _)| vowel == c) {
+            Some((_, marks)) if position == Some(index) => marked.push(marks[tone - 1]),
+            _ => marked.push(c),
+        }
+        if position.is_none() && index == 0 {
+            marked.push(COMBINING_MARKS[tone - 1]);
+        }
+    }
+    marked
+}
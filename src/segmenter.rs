@@ -0,0 +1,866 @@
+use jieba_rs::{Jieba, TokenizeMode};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::{error, fmt};
+
+use crate::batch::{encode_tokens, split_documents, BatchError};
+use crate::dict::{parse_dict, parse_idf, parse_word_list, DictError};
+use crate::dictionary::{Dictionary, DEFAULT_DICTIONARY};
+use crate::keywords::{
+    self, default_stop_words, TextRankExtractor, TextRankOptions, TfidfExtractor,
+};
+use crate::normalize::{normalize, NormalizeOptions, Normalized};
+use crate::offsets::{OffsetUnit, Offsets};
+use crate::pinyin::{pinyin, push_initials, PinyinStyle};
+use crate::sentences::split_sentences;
+use crate::snapshot::{self, SnapshotError};
+use crate::stream::TokenStream;
+use crate::tags::TagTable;
+use crate::types::{
+    DictWord, Keyword, PinyinToken, Sentence, SentenceTokens, Tag, TaggedToken, Token, TokenBuffer,
+    WordInfo, WordPinyin,
+};
+
+/// Returned by [`Segmenter::commit`] and [`Segmenter::rollback`] without a
+/// transaction to end
+#[derive(Debug)]
+pub(crate) struct TransactionError;
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no open transaction")
+    }
+}
+
+impl error::Error for TransactionError {}
+
+/// An independent Jieba instance with its own dictionary, shared by the
+/// wasm and the native bindings
+pub(crate) struct Segmenter {
+    /// Shared with the default dictionary or checkpoints, and copied on the
+    /// first change
+    dict: Arc<Dictionary>,
+    /// Dictionary the segmenter started with, restored by [`Segmenter::reset`]
+    initial: Arc<Dictionary>,
+    /// Dictionaries saved by [`Segmenter::begin`], innermost last
+    checkpoints: Vec<Arc<Dictionary>>,
+    stop_words: BTreeSet<String>,
+    /// IDF tables added by [`Segmenter::load_idf`] in `word idf` format,
+    /// loaded over jieba's table in order
+    idf_tables: Vec<String>,
+    /// TF-IDF extractor, built on first use and dropped when the dictionary
+    /// or the IDF tables change
+    tfidf: OnceCell<TfidfExtractor>,
+    /// TextRank extractor for the default parameters, built on first use and
+    /// dropped when the dictionary changes
+    textrank: OnceCell<TextRankExtractor>,
+    textrank_options: TextRankOptions,
+    normalize_options: NormalizeOptions,
+    traditional: bool,
+    tags: TagTable,
+}
+
+impl Default for Segmenter {
+    fn default() -> Self {
+        Segmenter::new()
+    }
+}
+
+impl Segmenter {
+    pub fn new() -> Segmenter {
+        Segmenter::with_dictionary(DEFAULT_DICTIONARY.clone())
+    }
+
+    /// Create a segmenter with an empty dictionary
+    pub fn empty() -> Segmenter {
+        Segmenter::with_dictionary(Arc::new(Dictionary::empty()))
+    }
+
+    /// Create a segmenter from a dictionary in `word freq tag` format
+    /// instead of the default one
+    pub fn with_dict(buf: &[u8]) -> Result<Segmenter, DictError> {
+        let mut segmenter = Segmenter::empty();
+        segmenter.load_dict(buf)?;
+        segmenter.initial = segmenter.dict.clone();
+        Ok(segmenter)
+    }
+
+    /// Create a segmenter from a dictionary snapshot, without building the
+    /// default dictionary
+    pub fn from_snapshot(buf: &[u8]) -> Result<Segmenter, SnapshotError> {
+        Ok(Segmenter::with_dictionary(Arc::new(snapshot::decode(buf)?)))
+    }
+
+    fn with_dictionary(dict: Arc<Dictionary>) -> Segmenter {
+        Segmenter {
+            initial: dict.clone(),
+            dict,
+            checkpoints: Vec::new(),
+            stop_words: default_stop_words(),
+            idf_tables: Vec::new(),
+            tfidf: OnceCell::new(),
+            textrank: OnceCell::new(),
+            textrank_options: TextRankOptions::default(),
+            normalize_options: NormalizeOptions::default(),
+            traditional: false,
+            tags: TagTable::default(),
+        }
+    }
+
+    /// The dictionary to change, copied first when it is shared
+    fn dict_mut(&mut self) -> &mut Dictionary {
+        // drop the extractors first, their references would force a copy
+        self.tfidf.take();
+        self.textrank.take();
+        Arc::make_mut(&mut self.dict)
+    }
+
+    /// Replace the dictionary
+    fn set_dict(&mut self, dict: Arc<Dictionary>) {
+        self.tfidf.take();
+        self.textrank.take();
+        self.dict = dict;
+    }
+
+    // =======================================================
+
+    /// Load a dictionary in `word freq tag` format.
+    ///
+    /// The whole buffer is validated first, so the dictionary is left
+    /// unchanged when any line is invalid. `word -` lines remove the word.
+    pub fn load_dict(&mut self, buf: &[u8]) -> Result<(), DictError> {
+        let entries = parse_dict(buf)?;
+        let dict = self.dict_mut();
+        // removing rebuilds jieba, so consecutive removals are applied at once
+        let mut removed = Vec::new();
+        for entry in &entries {
+            if entry.remove {
+                removed.push(entry.word.as_str());
+                continue;
+            }
+            dict.remove_words(removed.drain(..));
+            dict.add_word(
+                &entry.word,
+                Some(entry.freq.unwrap_or(0)),
+                Some(entry.tag.as_deref().unwrap_or("")),
+            );
+        }
+        dict.remove_words(removed);
+        Ok(())
+    }
+
+    pub fn add_word(&mut self, word: &str, freq: Option<usize>, tag: Option<&str>) -> usize {
+        self.dict_mut().add_word(word, freq, tag)
+    }
+
+    /// Remove a word and its tag, return `false` if it was not in the dictionary
+    pub fn remove_word(&mut self, word: &str) -> bool {
+        self.dict_mut().remove_words([word]) > 0
+    }
+
+    /// Remove many words at once, which is much faster than one by one,
+    /// return how many were in the dictionary
+    pub fn remove_words(&mut self, words: &[String]) -> usize {
+        self.dict_mut()
+            .remove_words(words.iter().map(String::as_str))
+    }
+
+    /// Frequency that forces `segment` to be cut as one word, and with `tune`
+    /// also give the word that frequency
+    pub fn suggest_freq(&mut self, segment: &str, tune: bool) -> usize {
+        let freq = self.dict.jieba().suggest_freq(segment);
+        if tune {
+            self.dict_mut().add_word(segment, Some(freq), None);
+        }
+        freq
+    }
+
+    /// Frequency that forces the joined `pieces` to be cut apart, and with
+    /// `tune` also give the joined word that frequency if it is in the
+    /// dictionary
+    pub fn suggest_split_freq(&mut self, pieces: &[String], tune: bool) -> usize {
+        let word = pieces.concat();
+        let current = self.dict.words().get(&word).map(|entry| entry.freq);
+        let total = self.dict.total() as f64;
+        let freq = pieces.iter().fold(1.0, |freq, piece| {
+            let piece_freq = self.dict.words().get(piece).map_or(1, |entry| entry.freq);
+            freq * piece_freq as f64 / total
+        });
+        let freq = ((freq * total) as usize).min(current.unwrap_or(0));
+        if tune && current.is_some() {
+            self.dict_mut().add_word(&word, Some(freq), None);
+        }
+        freq
+    }
+
+    /// Restore the dictionary the segmenter started with
+    pub fn reset(&mut self) {
+        self.set_dict(self.initial.clone());
+    }
+
+    /// Whether a word is in the dictionary, with its frequency and tag
+    pub fn lookup(&self, word: &str) -> WordInfo<'_> {
+        match self.dict.words().get(word) {
+            Some(entry) => WordInfo {
+                exists: true,
+                freq: entry.freq,
+                tag: self.dict.tag(entry),
+            },
+            None => WordInfo {
+                exists: false,
+                freq: 0,
+                tag: "",
+            },
+        }
+    }
+
+    /// Look up many words at once
+    pub fn lookup_batch(&self, words: &[String]) -> Vec<WordInfo<'_>> {
+        words.iter().map(|word| self.lookup(word)).collect()
+    }
+
+    /// Words starting with `prefix`, or with `fuzzy` also those starting
+    /// within one edit of it, most frequent first and at most `limit`, which
+    /// has to be at least 1
+    pub fn prefix_search(
+        &self,
+        prefix: &str,
+        limit: usize,
+        fuzzy: bool,
+    ) -> Result<Vec<DictWord<'_>>, String> {
+        Ok(self
+            .dict
+            .prefix_search(prefix, limit, fuzzy)?
+            .into_iter()
+            .map(|(word, entry)| DictWord {
+                word,
+                freq: entry.freq,
+                tag: self.dict.tag(entry),
+            })
+            .collect())
+    }
+
+    /// Write the dictionary in `word freq tag` format, or with `delta` only
+    /// the words removed, added or changed since the dictionary the segmenter
+    /// started with
+    pub fn export_dict(&self, delta: bool) -> String {
+        self.dict.export(delta.then(|| &*self.initial))
+    }
+
+    /// Write the whole dictionary into a binary snapshot
+    pub fn snapshot(&self) -> Vec<u8> {
+        snapshot::encode(&self.dict)
+    }
+
+    /// Replace the dictionary with the one of a snapshot.
+    ///
+    /// The dictionary is left unchanged when the snapshot is invalid.
+    pub fn load_snapshot(&mut self, buf: &[u8]) -> Result<(), SnapshotError> {
+        self.set_dict(Arc::new(snapshot::decode(buf)?));
+        Ok(())
+    }
+
+    // =======================================================
+
+    /// Start a transaction by saving the dictionary, return how many
+    /// transactions are open.
+    ///
+    /// Transactions nest. The dictionary is only copied on its first change
+    /// within a transaction, and the copy is kept until the transaction is
+    /// committed or rolled back.
+    pub fn begin(&mut self) -> usize {
+        self.checkpoints.push(self.dict.clone());
+        self.checkpoints.len()
+    }
+
+    /// Keep the dictionary changes of the innermost transaction
+    pub fn commit(&mut self) -> Result<(), TransactionError> {
+        self.checkpoints.pop().map(drop).ok_or(TransactionError)
+    }
+
+    /// Undo the dictionary changes of the innermost transaction
+    pub fn rollback(&mut self) -> Result<(), TransactionError> {
+        let dict = self.checkpoints.pop().ok_or(TransactionError)?;
+        self.set_dict(dict);
+        Ok(())
+    }
+
+    // =======================================================
+
+    /// Set the normalization run on texts before they are segmented
+    pub fn set_normalize_options(&mut self, options: NormalizeOptions) {
+        self.normalize_options = options;
+    }
+
+    /// The current normalization steps
+    pub fn normalize_options(&self) -> &NormalizeOptions {
+        &self.normalize_options
+    }
+
+    /// Normalize a text with the current normalization steps
+    pub fn normalize<'a>(&self, text: &'a str) -> Normalized<'a> {
+        normalize(text, &self.normalize_options)
+    }
+
+    /// Segment Traditional Chinese by converting it to Simplified Chinese,
+    /// words and positions still refer to the Traditional text
+    pub fn set_traditional(&mut self, traditional: bool) {
+        self.traditional = traditional;
+    }
+
+    pub fn traditional(&self) -> bool {
+        self.traditional
+    }
+
+    /// A text as it is segmented, normalized and converted
+    fn prepare<'a>(&self, text: &'a str) -> Normalized<'a> {
+        let normalized = self.normalize(text);
+        if self.traditional {
+            normalized.into_simplified()
+        } else {
+            normalized
+        }
+    }
+
+    // =======================================================
+
+    pub fn cut<'a>(&self, sentence: &'a str, hmm: bool) -> Vec<Cow<'a, str>> {
+        self.prepare(sentence)
+            .words(|text| self.dict.jieba().cut(text, hmm))
+    }
+
+    pub fn cut_all<'a>(&self, sentence: &'a str) -> Vec<Cow<'a, str>> {
+        self.prepare(sentence)
+            .words(|text| self.dict.jieba().cut_all(text))
+    }
+
+    pub fn cut_for_search<'a>(&self, sentence: &'a str, hmm: bool) -> Vec<Cow<'a, str>> {
+        self.prepare(sentence)
+            .words(|text| self.dict.jieba().cut_for_search(text, hmm))
+    }
+
+    // =======================================================
+
+    pub fn tag<'a>(&'a self, sentence: &'a str, hmm: bool) -> Vec<Tag<'a>> {
+        let jieba = self.dict.jieba();
+        let prepared = self.prepare(sentence);
+        match prepared.text {
+            Cow::Borrowed(text) => jieba.tag(text, hmm).into_iter().map(Tag::from).collect(),
+            Cow::Owned(ref text) => jieba
+                .tag(text, hmm)
+                .into_iter()
+                .map(|tag| {
+                    Tag {
+                        word: prepared
+                            .original(tag.word)
+                            .unwrap_or(Cow::Borrowed(tag.word)),
+                        tag: Cow::Borrowed(tag.tag),
+                    }
+                    .into_owned()
+                })
+                .collect(),
+        }
+    }
+
+    /// Tokenize a sentence, reporting positions in the given unit
+    pub fn tokenize<'a>(
+        &self,
+        sentence: &'a str,
+        mode: TokenizeMode,
+        hmm: bool,
+        unit: OffsetUnit,
+    ) -> Vec<Token<'a>> {
+        self.tokenize_at(sentence, &Offsets::new(sentence, unit), 0, mode, hmm)
+    }
+
+    /// Tokenize a part of a text starting at char position `base`, reporting
+    /// positions in the whole text through `offsets`
+    fn tokenize_at<'a>(
+        &self,
+        source: &'a str,
+        offsets: &Offsets,
+        base: usize,
+        mode: TokenizeMode,
+        hmm: bool,
+    ) -> Vec<Token<'a>> {
+        let prepared = self.prepare(source);
+        let jieba = self.dict.jieba();
+        match prepared.text {
+            Cow::Borrowed(text) => {
+                map_tokens(jieba.tokenize(text, mode, hmm), &prepared, offsets, base)
+            }
+            Cow::Owned(ref text) => {
+                map_tokens(jieba.tokenize(text, mode, hmm), &prepared, offsets, base)
+                    .into_iter()
+                    .map(Token::into_owned)
+                    .collect()
+            }
+        }
+    }
+
+    /// Split a document into sentences, reporting positions in the given
+    /// unit, see [`split_sentences`]
+    pub fn split_sentences<'a>(&self, text: &'a str, unit: OffsetUnit) -> Vec<Sentence<'a>> {
+        let offsets = Offsets::new(text, unit);
+        split_sentences(text)
+            .into_iter()
+            .map(|span| Sentence {
+                text: &text[span.bytes],
+                start: offsets.get(span.chars.start),
+                end: offsets.get(span.chars.end),
+            })
+            .collect()
+    }
+
+    /// Split a document into sentences and tokenize each of them, reporting
+    /// positions in the document in the given unit
+    pub fn tokenize_sentences<'a>(
+        &self,
+        text: &'a str,
+        mode: TokenizeMode,
+        hmm: bool,
+        unit: OffsetUnit,
+    ) -> Vec<SentenceTokens<'a>> {
+        let offsets = Offsets::new(text, unit);
+        split_sentences(text)
+            .into_iter()
+            .map(|span| {
+                let sentence = &text[span.bytes];
+                SentenceTokens {
+                    text: sentence,
+                    start: offsets.get(span.chars.start),
+                    end: offsets.get(span.chars.end),
+                    tokens: self.tokenize_at(sentence, &offsets, span.chars.start, mode, hmm),
+                }
+            })
+            .collect()
+    }
+
+    /// Tokenize a sentence with the tag of every token, reporting positions
+    /// in the given unit
+    pub fn tokenize_with_tags<'a>(
+        &'a self,
+        sentence: &'a str,
+        mode: TokenizeMode,
+        hmm: bool,
+        unit: OffsetUnit,
+    ) -> Vec<TaggedToken<'a>> {
+        tokenize_with_tags(
+            self.dict.jieba(),
+            self.prepare(sentence),
+            sentence,
+            mode,
+            hmm,
+            unit,
+        )
+    }
+
+    /// Tokenize a sentence into flat arrays of positions in the given unit
+    /// and, if `with_tags` is set, of tag ids, see [`Segmenter::tag_names`]
+    pub fn tokenize_binary(
+        &mut self,
+        sentence: &str,
+        mode: TokenizeMode,
+        hmm: bool,
+        unit: OffsetUnit,
+        with_tags: bool,
+    ) -> TokenBuffer {
+        let mut buffer = TokenBuffer::default();
+        if with_tags {
+            let tokens = tokenize_with_tags(
+                self.dict.jieba(),
+                self.prepare(sentence),
+                sentence,
+                mode,
+                hmm,
+                unit,
+            );
+            buffer.offsets.reserve(tokens.len() * 2);
+            buffer.tags.reserve(tokens.len());
+            for token in tokens {
+                buffer.offsets.push(token.start as u32);
+                buffer.offsets.push(token.end as u32);
+                buffer.tags.push(self.tags.id(&token.tag));
+            }
+        } else {
+            let tokens = self.tokenize(sentence, mode, hmm, unit);
+            buffer.offsets.reserve(tokens.len() * 2);
+            for token in tokens {
+                buffer.offsets.push(token.start as u32);
+                buffer.offsets.push(token.end as u32);
+            }
+        }
+        buffer
+    }
+
+    /// Start tokenizing a text pushed in chunks, with the current dictionary
+    pub fn token_stream(&self, mode: TokenizeMode, hmm: bool, unit: OffsetUnit) -> TokenStream {
+        TokenStream::new(
+            Arc::clone(&self.dict),
+            self.normalize_options.clone(),
+            self.traditional,
+            mode,
+            hmm,
+            unit,
+        )
+    }
+
+    /// Names of the tag ids handed out by [`Segmenter::tokenize_binary`]
+    pub fn tag_names(&self) -> &[String] {
+        self.tags.names()
+    }
+
+    /// Tokenize many documents in one call.
+    ///
+    /// `text` is the concatenation of all documents and `lengths` their
+    /// lengths in UTF-16 code units. The result holds, for each document in
+    /// order, its token count followed by the `start, end` pair of every
+    /// token in UTF-16 code units relative to the document.
+    pub fn tokenize_batch(
+        &self,
+        text: &str,
+        lengths: &[u32],
+        mode: TokenizeMode,
+        hmm: bool,
+    ) -> Result<Vec<u32>, BatchError> {
+        let mut output = Vec::with_capacity(lengths.len() + text.len());
+        for document in split_documents(text, lengths)? {
+            let tokens = self.tokenize(document, mode, hmm, OffsetUnit::Utf16);
+            encode_tokens(&tokens, &mut output);
+        }
+        Ok(output)
+    }
+
+    // =======================================================
+
+    /// Cut a sentence with the pinyin of every word, polyphonic characters
+    /// read as they are in the word
+    pub fn pinyin<'a>(
+        &self,
+        sentence: &'a str,
+        style: PinyinStyle,
+        hmm: bool,
+    ) -> Vec<WordPinyin<'a>> {
+        let jieba = self.dict.jieba();
+        let prepared = self.prepare(sentence);
+        match prepared.text {
+            Cow::Borrowed(text) => jieba
+                .cut(text, hmm)
+                .into_iter()
+                .map(|word| WordPinyin {
+                    word: Cow::Borrowed(word),
+                    pinyin: pinyin(word, style),
+                })
+                .collect(),
+            Cow::Owned(ref text) => jieba
+                .cut(text, hmm)
+                .into_iter()
+                .map(|word| WordPinyin {
+                    word: prepared
+                        .original(word)
+                        .unwrap_or_else(|| Cow::Owned(word.to_string())),
+                    pinyin: pinyin(word, style),
+                })
+                .collect(),
+        }
+    }
+
+    /// Tokenize a sentence with the pinyin of every token, reporting
+    /// positions in the given unit
+    pub fn tokenize_with_pinyin<'a>(
+        &self,
+        sentence: &'a str,
+        mode: TokenizeMode,
+        hmm: bool,
+        unit: OffsetUnit,
+        style: PinyinStyle,
+    ) -> Vec<PinyinToken<'a>> {
+        let jieba = self.dict.jieba();
+        let prepared = self.prepare(sentence);
+        let offsets = Offsets::new(sentence, unit);
+        match prepared.text {
+            Cow::Borrowed(text) => {
+                pinyin_tokens(jieba.tokenize(text, mode, hmm), &prepared, &offsets, style)
+            }
+            Cow::Owned(ref text) => {
+                pinyin_tokens(jieba.tokenize(text, mode, hmm), &prepared, &offsets, style)
+                    .into_iter()
+                    .map(PinyinToken::into_owned)
+                    .collect()
+            }
+        }
+    }
+
+    /// Abbreviate a sentence to the first letter of the pinyin of every
+    /// character, keeping other letters and digits
+    pub fn pinyin_initials(&self, sentence: &str, hmm: bool) -> String {
+        let prepared = self.prepare(sentence);
+        let mut initials = String::with_capacity(sentence.len());
+        for word in self.dict.jieba().cut(&prepared.text, hmm) {
+            push_initials(word, &mut initials);
+        }
+        initials
+    }
+
+    // =======================================================
+
+    /// Add a stop word ignored by keyword extraction, return `false` if it was already present
+    pub fn add_stop_word(&mut self, word: &str) -> bool {
+        let added = self.stop_words.insert(word.into());
+        self.update_stop_words();
+        added
+    }
+
+    /// Remove a stop word, return `false` if it was not present
+    pub fn remove_stop_word(&mut self, word: &str) -> bool {
+        let removed = self.stop_words.remove(word);
+        self.update_stop_words();
+        removed
+    }
+
+    /// Replace all stop words
+    pub fn set_stop_words(&mut self, stop_words: BTreeSet<String>) {
+        self.stop_words = stop_words;
+        self.update_stop_words();
+    }
+
+    /// Add the stop words of a file with one word per line
+    pub fn load_stop_words(&mut self, buf: &[u8]) -> Result<(), DictError> {
+        self.stop_words.extend(parse_word_list(buf)?);
+        self.update_stop_words();
+        Ok(())
+    }
+
+    /// Hand the stop words to the keyword extractors that are built
+    fn update_stop_words(&mut self) {
+        if let Some(tfidf) = self.tfidf.get_mut() {
+            tfidf.set_stop_words(&self.stop_words);
+        }
+        if let Some(textrank) = self.textrank.get_mut() {
+            textrank.set_stop_words(&self.stop_words);
+        }
+    }
+
+    /// The current stop words in sorted order
+    pub fn stop_words(&self) -> &BTreeSet<String> {
+        &self.stop_words
+    }
+
+    /// Merge an IDF table in `word idf` format into the one used by TF-IDF.
+    ///
+    /// The whole buffer is validated first, so the table is left unchanged
+    /// when any line is invalid. Like jieba-rs, words missing from every
+    /// table get the median IDF of the last table loaded.
+    pub fn load_idf(&mut self, buf: &[u8]) -> Result<(), DictError> {
+        let entries = parse_idf(buf)?;
+        if !entries.is_empty() {
+            let table = entries
+                .iter()
+                .map(|(word, idf)| format!("{} {}\n", word, idf))
+                .collect();
+            self.tfidf.take();
+            self.idf_tables.push(table);
+        }
+        Ok(())
+    }
+
+    /// Extract keywords by TF-IDF. The extractor is built on the first call
+    /// and kept until the dictionary or the IDF tables change, which parses
+    /// jieba's IDF table again. Without the `default-idf` feature there are
+    /// no keywords until an IDF table is loaded.
+    pub fn extract_tags_by_tfidf(
+        &self,
+        sentence: &str,
+        top_k: usize,
+        allowed_pos: &[String],
+    ) -> Vec<Keyword> {
+        if cfg!(not(feature = "default-idf")) && self.idf_tables.is_empty() {
+            return Vec::new();
+        }
+        let prepared = self.prepare(sentence);
+        let keywords = self
+            .tfidf
+            .get_or_init(|| {
+                TfidfExtractor::build(self.dict.clone(), &self.idf_tables, &self.stop_words)
+            })
+            .extract_tags(&prepared.text, top_k, allowed_pos);
+        original_keywords(&prepared, keywords)
+    }
+
+    /// Set the TextRank parameters
+    pub fn set_textrank_options(&mut self, options: TextRankOptions) -> Result<(), String> {
+        options.validate()?;
+        self.textrank_options = options;
+        Ok(())
+    }
+
+    /// The current TextRank parameters
+    pub fn textrank_options(&self) -> &TextRankOptions {
+        &self.textrank_options
+    }
+
+    /// Extract keywords by TextRank. With the default parameters, the
+    /// extractor of jieba-rs is built on the first call and kept until the
+    /// dictionary changes.
+    pub fn extract_tags_by_textrank(
+        &self,
+        sentence: &str,
+        top_k: usize,
+        allowed_pos: &[String],
+    ) -> Vec<Keyword> {
+        let prepared = self.prepare(sentence);
+        let keywords = if self.textrank_options == TextRankOptions::default() {
+            self.textrank
+                .get_or_init(|| TextRankExtractor::build(self.dict.clone(), &self.stop_words))
+                .extract_tags(&prepared.text, top_k, allowed_pos)
+        } else {
+            keywords::extract_tags_by_tuned_textrank(
+                self.dict.jieba(),
+                &self.stop_words,
+                &self.textrank_options,
+                &prepared.text,
+                top_k,
+                allowed_pos,
+            )
+        };
+        original_keywords(&prepared, keywords)
+    }
+}
+
+/// Keywords as they were before the conversion to Simplified Chinese, taken
+/// from their first occurrence in the text
+fn original_keywords(prepared: &Normalized<'_>, keywords: Vec<Keyword>) -> Vec<Keyword> {
+    keywords
+        .into_iter()
+        .map(|Keyword { keyword, weight }| {
+            let original = prepared
+                .text
+                .find(&keyword)
+                .and_then(|start| prepared.original(&prepared.text[start..start + keyword.len()]));
+            Keyword {
+                keyword: original.map_or(keyword, Cow::into_owned),
+                weight,
+            }
+        })
+        .collect()
+}
+
+/// Tokens of a text cut by jieba, with words and positions mapped back
+/// through the preparation to the source, positions shifted by `base` chars
+/// and then reported through `offsets`
+fn map_tokens<'a>(
+    tokens: Vec<jieba_rs::Token<'a>>,
+    prepared: &Normalized<'a>,
+    offsets: &Offsets,
+    base: usize,
+) -> Vec<Token<'a>> {
+    tokens
+        .into_iter()
+        .map(|token| {
+            let (start, end) = prepared.span(token.start, token.end);
+            Token {
+                word: prepared
+                    .original(token.word)
+                    .unwrap_or(Cow::Borrowed(token.word)),
+                start: offsets.get(base + start),
+                end: offsets.get(base + end),
+            }
+        })
+        .collect()
+}
+
+/// Tokens of a prepared text with their pinyin, read from the prepared
+/// words
+fn pinyin_tokens<'a>(
+    tokens: Vec<jieba_rs::Token<'a>>,
+    prepared: &Normalized<'a>,
+    offsets: &Offsets,
+    style: PinyinStyle,
+) -> Vec<PinyinToken<'a>> {
+    let readings = tokens
+        .iter()
+        .map(|token| pinyin(token.word, style))
+        .collect::<Vec<_>>();
+    map_tokens(tokens, prepared, offsets, 0)
+        .into_iter()
+        .zip(readings)
+        .map(|(token, pinyin)| PinyinToken {
+            word: token.word,
+            start: token.start,
+            end: token.end,
+            pinyin,
+        })
+        .collect()
+}
+
+/// Tokenize a sentence with the tag of every token, given as it is
+/// segmented, reporting positions in the given unit
+fn tokenize_with_tags<'a>(
+    jieba: &'a Jieba,
+    prepared: Normalized<'a>,
+    sentence: &'a str,
+    mode: TokenizeMode,
+    hmm: bool,
+    unit: OffsetUnit,
+) -> Vec<TaggedToken<'a>> {
+    let offsets = Offsets::new(sentence, unit);
+    match prepared.text {
+        Cow::Borrowed(text) => tag_tokens(jieba, text, &prepared, &offsets, mode, hmm),
+        Cow::Owned(ref text) => tag_tokens(jieba, text, &prepared, &offsets, mode, hmm)
+            .into_iter()
+            .map(TaggedToken::into_owned)
+            .collect(),
+    }
+}
+
+/// Tokenize a prepared text with the tag of every token
+fn tag_tokens<'a>(
+    jieba: &'a Jieba,
+    text: &'a str,
+    prepared: &Normalized<'a>,
+    offsets: &Offsets,
+    mode: TokenizeMode,
+    hmm: bool,
+) -> Vec<TaggedToken<'a>> {
+    let mut tags = jieba.tag(text, hmm).into_iter().peekable();
+    let mut position = 0;
+
+    jieba
+        .tokenize(text, mode, hmm)
+        .into_iter()
+        .map(|token| {
+            let tag = match tags.peek() {
+                // a word of the cut, tokens share its words in order
+                Some(tag) if token.start == position && token.word == tag.word => {
+                    let tag = tags.next().unwrap().tag;
+                    position = token.end;
+                    tag
+                }
+                // a sub-word emitted by search mode, tagged on its own
+                _ => match jieba.tag(token.word, false).as_slice() {
+                    [tag] => tag.tag,
+                    _ => tags.peek().map_or("x", |tag| tag.tag),
+                },
+            };
+
+            let (start, end) = prepared.span(token.start, token.end);
+            TaggedToken {
+                word: prepared
+                    .original(token.word)
+                    .unwrap_or(Cow::Borrowed(token.word)),
+                tag: Cow::Borrowed(tag),
+                start: offsets.get(start),
+                end: offsets.get(end),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn tokenize_mode(mode: u8) -> TokenizeMode {
+    match mode {
+        1 => TokenizeMode::Search,
+        _ => TokenizeMode::Default,
+    }
+}
@@ -0,0 +1,96 @@
+use std::ops::Range;
+
+/// Span of a sentence in both bytes and chars of its document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SentenceSpan {
+    pub bytes: Range<usize>,
+    pub chars: Range<usize>,
+}
+
+/// Sentence enders, a run of them like `？！` or `……` ends one sentence
+#[inline]
+fn is_terminator(c: char) -> bool {
+    matches!(c, '。' | '！' | '？' | '!' | '?' | '…' | '｡' | '．')
+}
+
+/// Closing quotes and brackets that belong to the sentence before them
+#[inline]
+fn is_closing(c: char) -> bool {
+    "”’」』）)】》〉]］}｝〕〗".contains(c)
+}
+
+/// ASCII quotes close the sentence only when nothing follows them directly,
+/// otherwise they may open the next one
+#[inline]
+fn is_ascii_quote(c: char) -> bool {
+    matches!(c, '"' | '\'')
+}
+
+/// Split a document into sentences.
+///
+/// A sentence ends after a run of sentence enders along with the closing
+/// quotes and brackets following it, and at every line break. A `.` only
+/// ends a sentence before whitespace or the end of the text, so numbers like
+/// `3.14` stay whole. Sentences do not include surrounding whitespace, and
+/// blank ones are skipped.
+pub(crate) fn split_sentences(text: &str) -> Vec<SentenceSpan> {
+    let chars = text.char_indices().collect::<Vec<_>>();
+    let mut sentences = Vec::new();
+    // start and end of the current sentence, as indices into `chars`
+    let mut current: Option<(usize, usize)> = None;
+    let mut i = 0;
+
+    let byte_offset = |i: usize| chars.get(i).map_or(text.len(), |&(offset, _)| offset);
+    let ends_here = |i: usize| match chars.get(i) {
+        None => true,
+        Some(&(_, c)) => {
+            c.is_whitespace() || is_terminator(c) || is_closing(c) || is_ascii_quote(c) || c == '.'
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i].1;
+        if c.is_whitespace() {
+            if c == '\n' || c == '\r' {
+                if let Some((start, end)) = current.take() {
+                    sentences.push(span(start, end, byte_offset));
+                }
+            }
+            i += 1;
+            continue;
+        }
+
+        let start = current.map_or(i, |(start, _)| start);
+        i += 1;
+        if is_terminator(c) || (c == '.' && ends_here(i)) {
+            while i < chars.len() {
+                let next = chars[i].1;
+                if is_terminator(next)
+                    || next == '.'
+                    || is_closing(next)
+                    || (is_ascii_quote(next) && ends_here(i + 1))
+                {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            sentences.push(span(start, i, byte_offset));
+            current = None;
+        } else {
+            current = Some((start, i));
+        }
+    }
+    if let Some((start, end)) = current {
+        sentences.push(span(start, end, byte_offset));
+    }
+
+    sentences
+}
+
+fn span(start: usize, end: usize, byte_offset: impl Fn(usize) -> usize) -> SentenceSpan {
+    SentenceSpan {
+        bytes: byte_offset(start)..byte_offset(end),
+        chars: start..end,
+    }
+}
@@ -0,0 +1,233 @@
+//! Compact, checked binary form of a dictionary.
+//!
+//! Layout, with every integer as a LEB128 varint:
+//!
+//! - the magic bytes `JBDS` and a version byte
+//! - the total frequency, checked on load
+//! - the tag count, then every tag as its byte length and bytes
+//! - the word count, then for every word in sorted order: the byte length
+//!   it shares with the previous word, the byte length and bytes of the
+//!   rest, its frequency and its tag index
+//! - the byte length and bytes of the prefix trie jieba-rs builds by inserting
+//!   the words in sorted order, so that loading skips inserting them again,
+//!   then its checksum as 8 little endian bytes, since jieba-rs trusts the
+//!   trie it walks
+
+use jieba_rs::Jieba;
+use std::{error, fmt};
+
+use crate::dictionary::Dictionary;
+use crate::tags::TagTable;
+
+const MAGIC: &[u8] = b"JBDS";
+const VERSION: u8 = 2;
+
+/// The snapshot is not one produced by [`encode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The buffer does not start with the snapshot magic bytes
+    InvalidHeader,
+    /// The snapshot was written by an incompatible version
+    UnsupportedVersion(u8),
+    /// The buffer ends in the middle of the snapshot
+    Truncated,
+    /// A word or tag is not valid UTF-8, the words are not sorted, or their
+    /// trie does not match its checksum
+    Corrupted,
+    /// The frequencies do not add up to the recorded total
+    TotalMismatch,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::InvalidHeader => write!(f, "not a dictionary snapshot"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            SnapshotError::Truncated => write!(f, "truncated snapshot"),
+            SnapshotError::Corrupted => write!(f, "corrupted snapshot"),
+            SnapshotError::TotalMismatch => {
+                write!(f, "snapshot frequencies do not match its total")
+            }
+        }
+    }
+}
+
+impl error::Error for SnapshotError {}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    write_varint(buf, bytes.len());
+    buf.extend_from_slice(bytes);
+}
+
+/// FxHash-style checksum of the trie, fast enough to check on every load
+fn checksum(bytes: &[u8]) -> u64 {
+    let mix =
+        |hash: u64, value: u64| (hash.rotate_left(5) ^ value).wrapping_mul(0x517c_c1b7_2722_0a95);
+    let chunks = bytes.chunks_exact(8);
+    let rest = chunks.remainder();
+    let hash = chunks.fold(0, |hash, chunk| {
+        mix(hash, u64::from_le_bytes(chunk.try_into().unwrap()))
+    });
+    rest.iter().fold(hash, |hash, &byte| mix(hash, byte as u64))
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], SnapshotError> {
+        if len > self.buf.len() {
+            return Err(SnapshotError::Truncated);
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    fn varint(&mut self) -> Result<usize, SnapshotError> {
+        let mut value = 0usize;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            value |= ((byte & 0x7f) as usize)
+                .checked_shl(shift)
+                .ok_or(SnapshotError::Corrupted)?;
+            if byte < 0x80 {
+                return Ok(value);
+            }
+        }
+        Err(SnapshotError::Corrupted)
+    }
+
+    fn string(&mut self) -> Result<&'a str, SnapshotError> {
+        let len = self.varint()?;
+        std::str::from_utf8(self.bytes(len)?).map_err(|_| SnapshotError::Corrupted)
+    }
+}
+
+/// Write every word of a dictionary into a snapshot
+pub(crate) fn encode(dictionary: &Dictionary) -> Vec<u8> {
+    let words = dictionary.words();
+    let mut tags = TagTable::default();
+    let tag_ids = words
+        .values()
+        .map(|entry| tags.id(dictionary.tag(entry)))
+        .collect::<Vec<_>>();
+
+    let mut buf = Vec::with_capacity(words.len() * 8);
+    buf.extend_from_slice(MAGIC);
+    buf.push(VERSION);
+    write_varint(&mut buf, dictionary.total());
+    write_varint(&mut buf, tags.names().len());
+    for tag in tags.names() {
+        write_bytes(&mut buf, tag.as_bytes());
+    }
+
+    write_varint(&mut buf, words.len());
+    let mut previous: &[u8] = &[];
+    for ((word, entry), tag_id) in words.iter().zip(tag_ids) {
+        let word = word.as_bytes();
+        let shared = previous
+            .iter()
+            .zip(word)
+            .take_while(|(a, b)| a == b)
+            .count();
+        write_varint(&mut buf, shared);
+        write_bytes(&mut buf, &word[shared..]);
+        write_varint(&mut buf, entry.freq);
+        write_varint(&mut buf, tag_id as usize);
+        previous = word;
+    }
+    // the trie depends on the order words were inserted in, so a dictionary
+    // that was not built in sorted order is built again to keep snapshots of
+    // the same words identical
+    let sorted = dictionary
+        .jieba()
+        .words()
+        .map(|(word, _, _)| word)
+        .eq(words.keys().map(String::as_str));
+    let trie = if sorted {
+        dictionary.jieba().trie_bytes()
+    } else {
+        Dictionary::from_sorted(
+            words
+                .iter()
+                .map(|(word, entry)| (word.as_str(), entry.freq, dictionary.tag(entry))),
+        )
+        .jieba()
+        .trie_bytes()
+    };
+    write_bytes(&mut buf, &trie);
+    buf.extend_from_slice(&checksum(&trie).to_le_bytes());
+    buf
+}
+
+/// Build the dictionary of a snapshot
+pub(crate) fn decode(buf: &[u8]) -> Result<Dictionary, SnapshotError> {
+    let mut reader = Reader { buf };
+    if reader.bytes(MAGIC.len()).ok() != Some(MAGIC) {
+        return Err(SnapshotError::InvalidHeader);
+    }
+    match reader.bytes(1)?[0] {
+        VERSION => {}
+        version => return Err(SnapshotError::UnsupportedVersion(version)),
+    }
+    let total = reader.varint()?;
+
+    let tag_count = reader.varint()?;
+    let tags = (0..tag_count)
+        .map(|_| reader.string())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let word_count = reader.varint()?;
+    let mut words = Vec::with_capacity(word_count.min(buf.len()));
+    let mut word = Vec::new();
+    let mut sum = 0usize;
+    for _ in 0..word_count {
+        let shared = reader.varint()?;
+        if shared > word.len() {
+            return Err(SnapshotError::Corrupted);
+        }
+        word.truncate(shared);
+        let len = reader.varint()?;
+        word.extend_from_slice(reader.bytes(len)?);
+        let freq = reader.varint()?;
+        let tag = tags.get(reader.varint()?).ok_or(SnapshotError::Corrupted)?;
+
+        let text = std::str::from_utf8(&word).map_err(|_| SnapshotError::Corrupted)?;
+        sum = sum.saturating_add(freq);
+        words.push((String::from(text), freq, *tag));
+    }
+    let trie_len = reader.varint()?;
+    let trie = reader.bytes(trie_len)?;
+    let trie_checksum = reader.bytes(8)?;
+
+    if !reader.buf.is_empty() {
+        return Err(SnapshotError::Corrupted);
+    }
+    if sum != total {
+        return Err(SnapshotError::TotalMismatch);
+    }
+    if words.windows(2).any(|pair| pair[0].0 >= pair[1].0)
+        || trie_checksum != checksum(trie).to_le_bytes()
+    {
+        return Err(SnapshotError::Corrupted);
+    }
+
+    let records = words
+        .iter()
+        .map(|(word, freq, tag)| (word.clone(), *freq, String::from(*tag)))
+        .collect();
+    let jieba = Jieba::from_trie_bytes(records, trie).ok_or(SnapshotError::Corrupted)?;
+    Ok(Dictionary::with_jieba(jieba, words))
+}
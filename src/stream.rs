@@ -0,0 +1,159 @@
+use jieba_rs::TokenizeMode;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use crate::dictionary::Dictionary;
+use crate::normalize::{composes, normalize, normalize_char, NormalizeOptions};
+use crate::offsets::{OffsetUnit, Offsets};
+use crate::types::OwnedToken;
+
+/// Text kept back without a safe place to cut it, before the last word is
+/// cut off anyway
+const MAX_PENDING: usize = 1 << 16;
+
+/// Characters jieba may join into one word, the others are always tokens of
+/// their own
+#[inline]
+fn is_word_char(c: char) -> bool {
+    matches!(c,
+        '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{2A6DF}'
+        | '\u{2A700}'..='\u{2EBEF}'
+        | '\u{2F800}'..='\u{2FA1F}'
+        | 'a'..='z'
+        | 'A'..='Z'
+        | '0'..='9'
+        | '+' | '#' | '&' | '.' | '_' | '%' | '-'
+    )
+}
+
+/// Byte position after the last character no word can span, where the text
+/// can be cut without changing how it is segmented. With normalization, the
+/// character must normalize to such characters only and not be composed with
+/// the next one.
+fn safe_end(text: &str, options: &NormalizeOptions) -> Option<usize> {
+    // first normalized character after the current one, if known yet
+    let mut next = None;
+    for (index, c) in text.char_indices().rev() {
+        let normalized = normalize_char(c, options);
+        let (first, last) = match (normalized.first(), normalized.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            // removed, it joins the characters around it
+            _ => continue,
+        };
+        // keep `\r\n` together, the `\n` may still be on its way
+        let crlf = last == '\r' && matches!(next, None | Some('\n'));
+        let composed = match next {
+            Some(next) => composes(last, next, options),
+            // a combining mark may still be on its way
+            None => options.nfkc,
+        };
+        let breaks = !normalized.iter().any(|&c| is_word_char(c));
+        if breaks && !crlf && !composed {
+            return Some(index + c.len_utf8());
+        }
+        next = Some(first);
+    }
+    None
+}
+
+/// Tokenizes a text pushed in chunks, emitting tokens as soon as no later
+/// chunk can change them.
+///
+/// It keeps the dictionary its segmenter had when the stream was created, so
+/// a whole text is segmented consistently.
+pub(crate) struct TokenStream {
+    dict: Arc<Dictionary>,
+    options: NormalizeOptions,
+    traditional: bool,
+    mode: TokenizeMode,
+    hmm: bool,
+    unit: OffsetUnit,
+    /// Text pushed but not tokenized yet
+    pending: String,
+    /// Position of `pending` in the whole text, in `unit`
+    offset: usize,
+}
+
+impl TokenStream {
+    pub fn new(
+        dict: Arc<Dictionary>,
+        options: NormalizeOptions,
+        traditional: bool,
+        mode: TokenizeMode,
+        hmm: bool,
+        unit: OffsetUnit,
+    ) -> Self {
+        TokenStream {
+            dict,
+            options,
+            traditional,
+            mode,
+            hmm,
+            unit,
+            pending: String::new(),
+            offset: 0,
+        }
+    }
+
+    /// Add a chunk of text, return the tokens completed by it.
+    ///
+    /// Text after the last character no word can span, such as punctuation
+    /// or whitespace, is kept until the next chunk. Without such a character
+    /// in [`MAX_PENDING`] bytes, all but the last word are emitted, which may
+    /// cut a little differently than the whole text would.
+    pub fn push(&mut self, chunk: &str) -> Vec<OwnedToken> {
+        self.pending.push_str(chunk);
+        let end = match safe_end(&self.pending, &self.options) {
+            Some(end) => end,
+            None if self.pending.len() > MAX_PENDING => {
+                let words = self.dict.jieba().cut(&self.pending, self.hmm);
+                self.pending.len() - words.last().map_or(0, |word| word.len())
+            }
+            None => 0,
+        };
+        self.emit(end)
+    }
+
+    /// Tokenize the rest of the text and start over with a new one
+    pub fn finish(&mut self) -> Vec<OwnedToken> {
+        let tokens = self.emit(self.pending.len());
+        self.offset = 0;
+        tokens
+    }
+
+    /// Tokenize the pending text up to the byte position `end`
+    fn emit(&mut self, end: usize) -> Vec<OwnedToken> {
+        if end == 0 {
+            return Vec::new();
+        }
+
+        let text = &self.pending[..end];
+        let offsets = Offsets::new(text, self.unit);
+        let mut prepared = normalize(text, &self.options);
+        if self.traditional {
+            prepared = prepared.into_simplified();
+        }
+        let tokens = self
+            .dict
+            .jieba()
+            .tokenize(&prepared.text, self.mode, self.hmm)
+            .into_iter()
+            .map(|token| {
+                let (start, end) = prepared.span(token.start, token.end);
+                OwnedToken {
+                    word: prepared
+                        .original(token.word)
+                        .map_or_else(|| token.word.to_string(), Cow::into_owned),
+                    start: self.offset + offsets.get(start),
+                    end: self.offset + offsets.get(end),
+                }
+            })
+            .collect();
+        self.offset += offsets.get(text.chars().count());
+        self.pending.drain(..end);
+        tokens
+    }
+}
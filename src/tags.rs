@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+/// POS tags numbered in order of first use, so tokens can carry a `u32`
+/// id instead of a string. Ids stay valid for the lifetime of the table.
+#[derive(Clone, Default)]
+pub(crate) struct TagTable {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl TagTable {
+    /// Id of a tag, assigning the next one on first use
+    pub fn id(&mut self, tag: &str) -> u32 {
+        if let Some(&id) = self.ids.get(tag) {
+            return id;
+        }
+        let id = self.names.len() as u32;
+        self.ids.insert(tag.into(), id);
+        self.names.push(tag.into());
+        id
+    }
+
+    /// Tag names indexed by id
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+}
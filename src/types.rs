@@ -0,0 +1,163 @@
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// A tagged word
+#[derive(Debug, Serialize)]
+pub(crate) struct Tag<'a> {
+    pub word: Cow<'a, str>,
+    pub tag: Cow<'a, str>,
+}
+
+impl<'a> From<jieba_rs::Tag<'a>> for Tag<'a> {
+    fn from(tag: jieba_rs::Tag<'a>) -> Self {
+        Tag {
+            word: Cow::Borrowed(tag.word),
+            tag: Cow::Borrowed(tag.tag),
+        }
+    }
+}
+
+impl Tag<'_> {
+    /// Copy the borrowed strings, to outlive a normalized text
+    pub fn into_owned(self) -> Tag<'static> {
+        Tag {
+            word: Cow::Owned(self.word.into_owned()),
+            tag: Cow::Owned(self.tag.into_owned()),
+        }
+    }
+}
+
+/// A word token with its span in the source string
+#[derive(Debug, Serialize)]
+pub(crate) struct Token<'a> {
+    pub word: Cow<'a, str>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Token<'_> {
+    /// Copy the borrowed word, to outlive a normalized text
+    pub fn into_owned(self) -> Token<'static> {
+        Token {
+            word: Cow::Owned(self.word.into_owned()),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+/// A word token owning its word, for tokens outliving their source string
+#[derive(Debug, Serialize)]
+pub(crate) struct OwnedToken {
+    pub word: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A tagged word token with its span in the source string
+#[derive(Debug, Serialize)]
+pub(crate) struct TaggedToken<'a> {
+    pub word: Cow<'a, str>,
+    pub tag: Cow<'a, str>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl TaggedToken<'_> {
+    /// Copy the borrowed strings, to outlive a normalized text
+    pub fn into_owned(self) -> TaggedToken<'static> {
+        TaggedToken {
+            word: Cow::Owned(self.word.into_owned()),
+            tag: Cow::Owned(self.tag.into_owned()),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+/// A word with its pinyin, one syllable per character
+#[derive(Debug, Serialize)]
+pub(crate) struct WordPinyin<'a> {
+    pub word: Cow<'a, str>,
+    pub pinyin: Vec<String>,
+}
+
+/// A word token with its span in the source string and its pinyin
+#[derive(Debug, Serialize)]
+pub(crate) struct PinyinToken<'a> {
+    pub word: Cow<'a, str>,
+    pub start: usize,
+    pub end: usize,
+    pub pinyin: Vec<String>,
+}
+
+impl PinyinToken<'_> {
+    /// Copy the borrowed word, to outlive a normalized text
+    pub fn into_owned(self) -> PinyinToken<'static> {
+        PinyinToken {
+            word: Cow::Owned(self.word.into_owned()),
+            start: self.start,
+            end: self.end,
+            pinyin: self.pinyin,
+        }
+    }
+}
+
+/// A sentence with its span in the source document
+#[derive(Debug, Serialize)]
+pub(crate) struct Sentence<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A sentence with its tokens, all spans in the source document
+#[derive(Debug, Serialize)]
+pub(crate) struct SentenceTokens<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+    pub tokens: Vec<Token<'a>>,
+}
+
+/// State of a word in the dictionary, zero frequency and empty tag when missing
+#[derive(Debug, Serialize)]
+pub(crate) struct WordInfo<'a> {
+    pub exists: bool,
+    pub freq: usize,
+    pub tag: &'a str,
+}
+
+/// A dictionary word with its frequency and tag
+#[derive(Debug, Serialize)]
+pub(crate) struct DictWord<'a> {
+    pub word: &'a str,
+    pub freq: usize,
+    pub tag: &'a str,
+}
+
+/// A keyword with its weight
+#[derive(Debug, Serialize)]
+pub(crate) struct Keyword {
+    pub keyword: String,
+    pub weight: f64,
+}
+
+impl From<jieba_rs::Keyword> for Keyword {
+    fn from(keyword: jieba_rs::Keyword) -> Self {
+        Keyword {
+            keyword: keyword.keyword,
+            weight: keyword.weight,
+        }
+    }
+}
+
+/// Tokens of a sentence as flat arrays, for callers reading them straight
+/// from memory instead of one object per token
+#[derive(Debug, Default)]
+pub(crate) struct TokenBuffer {
+    /// `start, end` pair of every token
+    pub offsets: Vec<u32>,
+    /// Tag id of every token, empty unless tags were requested
+    pub tags: Vec<u32>,
+}
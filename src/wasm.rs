@@ -0,0 +1,778 @@
+use lazy_static::lazy_static;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+use wasm_bindgen::prelude::*;
+
+use crate::convert;
+use crate::keywords::TextRankOptions;
+use crate::normalize::NormalizeOptions;
+use crate::offsets::OffsetUnit;
+use crate::pinyin::PinyinStyle;
+use crate::segmenter::{tokenize_mode, Segmenter};
+use crate::stream::TokenStream;
+use crate::types::TokenBuffer;
+use crate::MUTEXERROR;
+
+const SERDEERROR: &str = "SerdeError";
+
+lazy_static! {
+    /// Instance behind the module level functions, `None` until first use
+    static ref JIEBA: Mutex<Option<JsSegmenter>> = Mutex::new(None);
+}
+
+/// Lock of the default instance
+struct DefaultSegmenter(MutexGuard<'static, Option<JsSegmenter>>);
+
+impl Deref for DefaultSegmenter {
+    type Target = JsSegmenter;
+
+    fn deref(&self) -> &JsSegmenter {
+        // always set by `default_segmenter`
+        self.0.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for DefaultSegmenter {
+    fn deref_mut(&mut self) -> &mut JsSegmenter {
+        self.0.as_mut().unwrap()
+    }
+}
+
+/// Lock the default instance, creating it with the default dictionary on
+/// first use
+fn default_segmenter() -> DefaultSegmenter {
+    let mut jieba = JIEBA.lock().expect(MUTEXERROR);
+    jieba.get_or_insert_with(JsSegmenter::new);
+    DefaultSegmenter(jieba)
+}
+
+/// Convert a serializable value into a plain JS value (array / object)
+fn to_js<T: Serialize + ?Sized>(value: &T) -> JsValue {
+    serde_wasm_bindgen::to_value(value).expect(SERDEERROR)
+}
+
+/// Convert a plain JS value (array / object) into a deserializable value
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<T, serde_wasm_bindgen::Error> {
+    serde_wasm_bindgen::from_value(value)
+}
+
+/// An independent Jieba instance with its own dictionary
+#[wasm_bindgen(js_name = Segmenter)]
+#[derive(Default)]
+pub struct JsSegmenter {
+    segmenter: Segmenter,
+}
+
+/// Tokens kept in wasm memory as flat `u32` arrays, read by JS through
+/// views at the given addresses without copying
+#[wasm_bindgen(js_name = TokenBuffer)]
+pub struct JsTokenBuffer {
+    buffer: TokenBuffer,
+}
+
+#[wasm_bindgen(js_class = TokenBuffer)]
+impl JsTokenBuffer {
+    /// Number of tokens
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.buffer.offsets.len() / 2
+    }
+
+    /// Address of the `start, end` pairs, `2 * length` values
+    pub fn offsets_ptr(&self) -> *const u32 {
+        self.buffer.offsets.as_ptr()
+    }
+
+    /// Address of the tag ids, `length` values if tags were requested
+    pub fn tags_ptr(&self) -> *const u32 {
+        self.buffer.tags.as_ptr()
+    }
+}
+
+/// Tokenizes a text pushed in chunks, see `Segmenter::token_stream`
+#[wasm_bindgen(js_name = TokenStream)]
+pub struct JsTokenStream {
+    stream: TokenStream,
+}
+
+#[wasm_bindgen(js_class = TokenStream)]
+impl JsTokenStream {
+    /// Add a chunk of text, return the tokens completed by it
+    pub fn push(&mut self, chunk: &str) -> JsValue {
+        to_js(&self.stream.push(chunk))
+    }
+
+    /// Tokenize the rest of the text and start over with a new one
+    pub fn finish(&mut self) -> JsValue {
+        to_js(&self.stream.finish())
+    }
+}
+
+#[wasm_bindgen(js_class = Segmenter)]
+impl JsSegmenter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> JsSegmenter {
+        JsSegmenter {
+            segmenter: Segmenter::new(),
+        }
+    }
+
+    /// Create an instance with an empty dictionary
+    pub fn empty() -> JsSegmenter {
+        JsSegmenter {
+            segmenter: Segmenter::empty(),
+        }
+    }
+
+    /// Create an instance from a dictionary in `word freq tag` format
+    /// instead of the default one
+    pub fn with_dict(buf: &[u8]) -> Result<JsSegmenter, JsError> {
+        Ok(JsSegmenter {
+            segmenter: Segmenter::with_dict(buf)?,
+        })
+    }
+
+    /// Create an instance from a dictionary snapshot, without building the
+    /// default dictionary
+    pub fn from_snapshot(buf: &[u8]) -> Result<JsSegmenter, JsError> {
+        Ok(JsSegmenter {
+            segmenter: Segmenter::from_snapshot(buf)?,
+        })
+    }
+
+    // =======================================================
+
+    /// Load a dictionary in `word freq tag` format.
+    ///
+    /// The whole buffer is validated first, so the dictionary is left
+    /// unchanged when any line is invalid.
+    pub fn load_dict(&mut self, buf: &[u8]) -> Result<(), JsError> {
+        Ok(self.segmenter.load_dict(buf)?)
+    }
+
+    pub fn add_word(&mut self, word: &str, freq: i32, tag: &str) -> usize {
+        self.segmenter.add_word(
+            word,
+            if freq < 0 { None } else { Some(freq as usize) },
+            if tag.is_empty() { None } else { Some(tag) },
+        )
+    }
+
+    /// Remove a word and its tag, return `false` if it was not in the dictionary
+    pub fn remove_word(&mut self, word: &str) -> bool {
+        self.segmenter.remove_word(word)
+    }
+
+    /// Remove the given array of words at once, which is much faster than
+    /// one by one, return how many were in the dictionary
+    pub fn remove_words(&mut self, words: JsValue) -> Result<usize, JsError> {
+        Ok(self.segmenter.remove_words(&from_js::<Vec<String>>(words)?))
+    }
+
+    /// Frequency that forces a word to be joined, applied with `tune`
+    pub fn suggest_freq(&mut self, segment: &str, tune: bool) -> usize {
+        self.segmenter.suggest_freq(segment, tune)
+    }
+
+    /// Frequency that forces the given array of pieces apart, applied with `tune`
+    pub fn suggest_split_freq(&mut self, pieces: JsValue, tune: bool) -> Result<usize, JsError> {
+        Ok(self
+            .segmenter
+            .suggest_split_freq(&from_js::<Vec<String>>(pieces)?, tune))
+    }
+
+    pub fn reset(&mut self) {
+        self.segmenter.reset()
+    }
+
+    /// Whether a word is in the dictionary, with its frequency and tag
+    pub fn lookup(&self, word: &str) -> JsValue {
+        to_js(&self.segmenter.lookup(word))
+    }
+
+    /// Look up the given array of words at once
+    pub fn lookup_batch(&self, words: JsValue) -> Result<JsValue, JsError> {
+        Ok(to_js(
+            &self.segmenter.lookup_batch(&from_js::<Vec<String>>(words)?),
+        ))
+    }
+
+    /// Words starting with `prefix`, or with `fuzzy` also those starting
+    /// within one edit of it, most frequent first and at most `limit`, which
+    /// has to be at least 1
+    pub fn prefix_search(
+        &self,
+        prefix: &str,
+        limit: usize,
+        fuzzy: bool,
+    ) -> Result<JsValue, JsError> {
+        self.segmenter
+            .prefix_search(prefix, limit, fuzzy)
+            .map(|words| to_js(&words))
+            .map_err(|err| JsError::new(&err))
+    }
+
+    /// Write the dictionary in `word freq tag` format, or with `delta` only
+    /// the words removed, added or changed since the dictionary it started
+    /// with
+    pub fn export_dict(&self, delta: bool) -> String {
+        self.segmenter.export_dict(delta)
+    }
+
+    /// Write the whole dictionary into a binary snapshot
+    pub fn snapshot(&self) -> Vec<u8> {
+        self.segmenter.snapshot()
+    }
+
+    /// Replace the dictionary with the one of a snapshot, left unchanged
+    /// when the snapshot is invalid
+    pub fn load_snapshot(&mut self, buf: &[u8]) -> Result<(), JsError> {
+        Ok(self.segmenter.load_snapshot(buf)?)
+    }
+
+    // =======================================================
+
+    /// Start a transaction around dictionary changes, return how many are open
+    pub fn begin(&mut self) -> usize {
+        self.segmenter.begin()
+    }
+
+    /// Keep the dictionary changes of the innermost transaction
+    pub fn commit(&mut self) -> Result<(), JsError> {
+        Ok(self.segmenter.commit()?)
+    }
+
+    /// Undo the dictionary changes of the innermost transaction
+    pub fn rollback(&mut self) -> Result<(), JsError> {
+        Ok(self.segmenter.rollback()?)
+    }
+
+    // =======================================================
+
+    pub fn cut(&self, sentence: &str, hmm: u8) -> JsValue {
+        to_js(&self.segmenter.cut(sentence, hmm == 1))
+    }
+
+    pub fn cut_all(&self, sentence: &str) -> JsValue {
+        to_js(&self.segmenter.cut_all(sentence))
+    }
+
+    pub fn cut_for_search(&self, sentence: &str, hmm: u8) -> JsValue {
+        to_js(&self.segmenter.cut_for_search(sentence, hmm == 1))
+    }
+
+    // =======================================================
+
+    pub fn tag(&self, sentence: &str, hmm: u8) -> JsValue {
+        to_js(&self.segmenter.tag(sentence, hmm == 1))
+    }
+
+    /// Tokenize a sentence, reporting positions in the given unit
+    /// (0: chars, 1: UTF-8 bytes, 2: UTF-16 code units)
+    pub fn tokenize(&self, sentence: &str, mode: u8, hmm: u8, unit: u8) -> JsValue {
+        to_js(&self.segmenter.tokenize(
+            sentence,
+            tokenize_mode(mode),
+            hmm == 1,
+            OffsetUnit::from(unit),
+        ))
+    }
+
+    /// Split a document into sentences, reporting positions in the given
+    /// unit (0: chars, 1: UTF-8 bytes, 2: UTF-16 code units)
+    pub fn split_sentences(&self, text: &str, unit: u8) -> JsValue {
+        to_js(&self.segmenter.split_sentences(text, OffsetUnit::from(unit)))
+    }
+
+    /// Tokenize a document sentence by sentence, reporting positions in the
+    /// document in the given unit
+    pub fn tokenize_sentences(&self, text: &str, mode: u8, hmm: u8, unit: u8) -> JsValue {
+        to_js(&self.segmenter.tokenize_sentences(
+            text,
+            tokenize_mode(mode),
+            hmm == 1,
+            OffsetUnit::from(unit),
+        ))
+    }
+
+    /// Tokenize a sentence with the tag of every token, reporting positions
+    /// in the given unit (0: chars, 1: UTF-8 bytes, 2: UTF-16 code units)
+    pub fn tokenize_with_tags(&self, sentence: &str, mode: u8, hmm: u8, unit: u8) -> JsValue {
+        to_js(&self.segmenter.tokenize_with_tags(
+            sentence,
+            tokenize_mode(mode),
+            hmm == 1,
+            OffsetUnit::from(unit),
+        ))
+    }
+
+    /// Tokenize a sentence into flat arrays of positions in the given unit
+    /// and, if `with_tags` is set, of tag ids, see `tag_names`
+    pub fn tokenize_binary(
+        &mut self,
+        sentence: &str,
+        mode: u8,
+        hmm: u8,
+        unit: u8,
+        with_tags: bool,
+    ) -> JsTokenBuffer {
+        JsTokenBuffer {
+            buffer: self.segmenter.tokenize_binary(
+                sentence,
+                tokenize_mode(mode),
+                hmm == 1,
+                OffsetUnit::from(unit),
+                with_tags,
+            ),
+        }
+    }
+
+    /// Start tokenizing a text pushed in chunks, reporting positions in the
+    /// whole text in the given unit
+    pub fn token_stream(&self, mode: u8, hmm: u8, unit: u8) -> JsTokenStream {
+        JsTokenStream {
+            stream: self.segmenter.token_stream(
+                tokenize_mode(mode),
+                hmm == 1,
+                OffsetUnit::from(unit),
+            ),
+        }
+    }
+
+    /// Names of the tag ids returned by `tokenize_binary`, indexed by id
+    pub fn tag_names(&self) -> JsValue {
+        to_js(self.segmenter.tag_names())
+    }
+
+    /// Tokenize many documents in one call.
+    ///
+    /// `text` is the concatenation of all documents and `lengths` their
+    /// lengths in UTF-16 code units. The result holds, for each document in
+    /// order, its token count followed by the `start, end` pair of every
+    /// token in UTF-16 code units relative to the document.
+    pub fn tokenize_batch(
+        &self,
+        text: &str,
+        lengths: &[u32],
+        mode: u8,
+        hmm: u8,
+    ) -> Result<Vec<u32>, JsError> {
+        Ok(self
+            .segmenter
+            .tokenize_batch(text, lengths, tokenize_mode(mode), hmm == 1)?)
+    }
+
+    // =======================================================
+
+    /// Cut a sentence with the pinyin of every word, in the given style
+    /// (0: tone marks, 1: tone numbers, 2: plain, 3: first letters)
+    pub fn pinyin(&self, sentence: &str, style: u8, hmm: u8) -> JsValue {
+        to_js(
+            &self
+                .segmenter
+                .pinyin(sentence, PinyinStyle::from(style), hmm == 1),
+        )
+    }
+
+    /// Tokenize a sentence with the pinyin of every token, reporting
+    /// positions in the given unit
+    pub fn tokenize_with_pinyin(
+        &self,
+        sentence: &str,
+        mode: u8,
+        hmm: u8,
+        unit: u8,
+        style: u8,
+    ) -> JsValue {
+        to_js(&self.segmenter.tokenize_with_pinyin(
+            sentence,
+            tokenize_mode(mode),
+            hmm == 1,
+            OffsetUnit::from(unit),
+            PinyinStyle::from(style),
+        ))
+    }
+
+    /// Abbreviate a sentence to the first letters of its pinyin
+    pub fn pinyin_initials(&self, sentence: &str, hmm: u8) -> String {
+        self.segmenter.pinyin_initials(sentence, hmm == 1)
+    }
+
+    // =======================================================
+
+    /// Add a stop word ignored by keyword extraction, return `false` if it was already present
+    pub fn add_stop_word(&mut self, word: &str) -> bool {
+        self.segmenter.add_stop_word(word)
+    }
+
+    /// Remove a stop word, return `false` if it was not present
+    pub fn remove_stop_word(&mut self, word: &str) -> bool {
+        self.segmenter.remove_stop_word(word)
+    }
+
+    /// Replace all stop words with the given array of words
+    pub fn set_stop_words(&mut self, stop_words: JsValue) -> Result<(), JsError> {
+        self.segmenter
+            .set_stop_words(from_js::<BTreeSet<String>>(stop_words)?);
+        Ok(())
+    }
+
+    /// Add the stop words of a file with one word per line
+    pub fn load_stop_words(&mut self, buf: &[u8]) -> Result<(), JsError> {
+        Ok(self.segmenter.load_stop_words(buf)?)
+    }
+
+    /// List the current stop words in sorted order
+    pub fn get_stop_words(&self) -> JsValue {
+        to_js(self.segmenter.stop_words())
+    }
+
+    /// Merge an IDF table in `word idf` format into the one used by TF-IDF.
+    ///
+    /// The whole buffer is validated first, so the table is left unchanged
+    /// when any line is invalid.
+    pub fn load_idf(&mut self, buf: &[u8]) -> Result<(), JsError> {
+        Ok(self.segmenter.load_idf(buf)?)
+    }
+
+    pub fn extract_tags_by_tfidf(
+        &self,
+        sentence: &str,
+        top_k: usize,
+        allowed_pos: JsValue,
+    ) -> Result<JsValue, JsError> {
+        Ok(to_js(&self.segmenter.extract_tags_by_tfidf(
+            sentence,
+            top_k,
+            &from_js::<Vec<String>>(allowed_pos)?,
+        )))
+    }
+
+    /// Set the TextRank parameters, missing fields fall back to their defaults
+    pub fn set_textrank_options(&mut self, options: JsValue) -> Result<(), JsError> {
+        self.segmenter
+            .set_textrank_options(from_js::<TextRankOptions>(options)?)
+            .map_err(|err| JsError::new(&err))
+    }
+
+    /// Get the current TextRank parameters
+    pub fn get_textrank_options(&self) -> JsValue {
+        to_js(self.segmenter.textrank_options())
+    }
+
+    pub fn extract_tags_by_textrank(
+        &self,
+        sentence: &str,
+        top_k: usize,
+        allowed_pos: JsValue,
+    ) -> Result<JsValue, JsError> {
+        Ok(to_js(&self.segmenter.extract_tags_by_textrank(
+            sentence,
+            top_k,
+            &from_js::<Vec<String>>(allowed_pos)?,
+        )))
+    }
+
+    /// Set the normalization run before segmentation, missing steps are off
+    pub fn set_normalize_options(&mut self, options: JsValue) -> Result<(), JsError> {
+        self.segmenter
+            .set_normalize_options(from_js::<NormalizeOptions>(options)?);
+        Ok(())
+    }
+
+    /// Get the current normalization steps
+    pub fn get_normalize_options(&self) -> JsValue {
+        to_js(self.segmenter.normalize_options())
+    }
+
+    /// Normalize a text the way it is before segmentation
+    pub fn normalize(&self, text: &str) -> String {
+        self.segmenter.normalize(text).text.into_owned()
+    }
+
+    /// Segment Traditional Chinese by converting it to Simplified Chinese
+    pub fn set_traditional(&mut self, traditional: bool) {
+        self.segmenter.set_traditional(traditional)
+    }
+
+    pub fn is_traditional(&self) -> bool {
+        self.segmenter.traditional()
+    }
+}
+
+// =======================================================
+
+#[wasm_bindgen]
+pub fn load_dict(buf: &[u8]) -> Result<(), JsError> {
+    default_segmenter().load_dict(buf)
+}
+
+#[wasm_bindgen]
+pub fn add_word(word: &str, freq: i32, tag: &str) -> usize {
+    default_segmenter().add_word(word, freq, tag)
+}
+
+#[wasm_bindgen]
+pub fn remove_word(word: &str) -> bool {
+    default_segmenter().remove_word(word)
+}
+
+#[wasm_bindgen]
+pub fn remove_words(words: JsValue) -> Result<usize, JsError> {
+    default_segmenter().remove_words(words)
+}
+
+#[wasm_bindgen]
+pub fn suggest_freq(segment: &str, tune: bool) -> usize {
+    default_segmenter().suggest_freq(segment, tune)
+}
+
+#[wasm_bindgen]
+pub fn suggest_split_freq(pieces: JsValue, tune: bool) -> Result<usize, JsError> {
+    default_segmenter().suggest_split_freq(pieces, tune)
+}
+
+#[wasm_bindgen]
+pub fn reset() {
+    default_segmenter().reset()
+}
+
+#[wasm_bindgen]
+pub fn lookup(word: &str) -> JsValue {
+    default_segmenter().lookup(word)
+}
+
+#[wasm_bindgen]
+pub fn lookup_batch(words: JsValue) -> Result<JsValue, JsError> {
+    default_segmenter().lookup_batch(words)
+}
+
+#[wasm_bindgen]
+pub fn prefix_search(prefix: &str, limit: usize, fuzzy: bool) -> Result<JsValue, JsError> {
+    default_segmenter().prefix_search(prefix, limit, fuzzy)
+}
+
+#[wasm_bindgen]
+pub fn export_dict(delta: bool) -> String {
+    default_segmenter().export_dict(delta)
+}
+
+#[wasm_bindgen]
+pub fn snapshot() -> Vec<u8> {
+    default_segmenter().snapshot()
+}
+
+#[wasm_bindgen]
+pub fn load_snapshot(buf: &[u8]) -> Result<(), JsError> {
+    let mut jieba = JIEBA.lock().expect(MUTEXERROR);
+    match jieba.as_mut() {
+        Some(jieba) => jieba.load_snapshot(buf),
+        // skip building the default dictionary only to replace it
+        None => {
+            *jieba = Some(JsSegmenter::from_snapshot(buf)?);
+            Ok(())
+        }
+    }
+}
+
+// =======================================================
+
+#[wasm_bindgen]
+pub fn begin() -> usize {
+    default_segmenter().begin()
+}
+
+#[wasm_bindgen]
+pub fn commit() -> Result<(), JsError> {
+    default_segmenter().commit()
+}
+
+#[wasm_bindgen]
+pub fn rollback() -> Result<(), JsError> {
+    default_segmenter().rollback()
+}
+
+// =======================================================
+
+#[wasm_bindgen]
+pub fn cut(sentence: &str, hmm: u8) -> JsValue {
+    default_segmenter().cut(sentence, hmm)
+}
+
+#[wasm_bindgen]
+pub fn cut_all(sentence: &str) -> JsValue {
+    default_segmenter().cut_all(sentence)
+}
+
+#[wasm_bindgen]
+pub fn cut_for_search(sentence: &str, hmm: u8) -> JsValue {
+    default_segmenter().cut_for_search(sentence, hmm)
+}
+
+// =======================================================
+
+#[wasm_bindgen]
+pub fn tag(sentence: &str, hmm: u8) -> JsValue {
+    default_segmenter().tag(sentence, hmm)
+}
+
+#[wasm_bindgen]
+pub fn tokenize(sentence: &str, mode: u8, hmm: u8, unit: u8) -> JsValue {
+    default_segmenter().tokenize(sentence, mode, hmm, unit)
+}
+
+#[wasm_bindgen]
+pub fn split_sentences(text: &str, unit: u8) -> JsValue {
+    default_segmenter().split_sentences(text, unit)
+}
+
+#[wasm_bindgen]
+pub fn tokenize_sentences(text: &str, mode: u8, hmm: u8, unit: u8) -> JsValue {
+    default_segmenter().tokenize_sentences(text, mode, hmm, unit)
+}
+
+#[wasm_bindgen]
+pub fn tokenize_with_tags(sentence: &str, mode: u8, hmm: u8, unit: u8) -> JsValue {
+    default_segmenter().tokenize_with_tags(sentence, mode, hmm, unit)
+}
+
+#[wasm_bindgen]
+pub fn tokenize_binary(
+    sentence: &str,
+    mode: u8,
+    hmm: u8,
+    unit: u8,
+    with_tags: bool,
+) -> JsTokenBuffer {
+    default_segmenter().tokenize_binary(sentence, mode, hmm, unit, with_tags)
+}
+
+#[wasm_bindgen]
+pub fn token_stream(mode: u8, hmm: u8, unit: u8) -> JsTokenStream {
+    default_segmenter().token_stream(mode, hmm, unit)
+}
+
+#[wasm_bindgen]
+pub fn tag_names() -> JsValue {
+    default_segmenter().tag_names()
+}
+
+#[wasm_bindgen]
+pub fn tokenize_batch(text: &str, lengths: &[u32], mode: u8, hmm: u8) -> Result<Vec<u32>, JsError> {
+    default_segmenter().tokenize_batch(text, lengths, mode, hmm)
+}
+
+#[wasm_bindgen]
+pub fn pinyin(sentence: &str, style: u8, hmm: u8) -> JsValue {
+    default_segmenter().pinyin(sentence, style, hmm)
+}
+
+#[wasm_bindgen]
+pub fn tokenize_with_pinyin(sentence: &str, mode: u8, hmm: u8, unit: u8, style: u8) -> JsValue {
+    default_segmenter().tokenize_with_pinyin(sentence, mode, hmm, unit, style)
+}
+
+#[wasm_bindgen]
+pub fn pinyin_initials(sentence: &str, hmm: u8) -> String {
+    default_segmenter().pinyin_initials(sentence, hmm)
+}
+
+// =======================================================
+
+#[wasm_bindgen]
+pub fn load_idf(buf: &[u8]) -> Result<(), JsError> {
+    default_segmenter().load_idf(buf)
+}
+
+#[wasm_bindgen]
+pub fn add_stop_word(word: &str) -> bool {
+    default_segmenter().add_stop_word(word)
+}
+
+#[wasm_bindgen]
+pub fn remove_stop_word(word: &str) -> bool {
+    default_segmenter().remove_stop_word(word)
+}
+
+#[wasm_bindgen]
+pub fn set_stop_words(stop_words: JsValue) -> Result<(), JsError> {
+    default_segmenter().set_stop_words(stop_words)
+}
+
+#[wasm_bindgen]
+pub fn load_stop_words(buf: &[u8]) -> Result<(), JsError> {
+    default_segmenter().load_stop_words(buf)
+}
+
+#[wasm_bindgen]
+pub fn get_stop_words() -> JsValue {
+    default_segmenter().get_stop_words()
+}
+
+#[wasm_bindgen]
+pub fn extract_tags_by_tfidf(
+    sentence: &str,
+    top_k: usize,
+    allowed_pos: JsValue,
+) -> Result<JsValue, JsError> {
+    default_segmenter().extract_tags_by_tfidf(sentence, top_k, allowed_pos)
+}
+
+#[wasm_bindgen]
+pub fn extract_tags_by_textrank(
+    sentence: &str,
+    top_k: usize,
+    allowed_pos: JsValue,
+) -> Result<JsValue, JsError> {
+    default_segmenter().extract_tags_by_textrank(sentence, top_k, allowed_pos)
+}
+
+#[wasm_bindgen]
+pub fn set_textrank_options(options: JsValue) -> Result<(), JsError> {
+    default_segmenter().set_textrank_options(options)
+}
+
+#[wasm_bindgen]
+pub fn get_textrank_options() -> JsValue {
+    default_segmenter().get_textrank_options()
+}
+
+// =======================================================
+
+#[wasm_bindgen]
+pub fn set_normalize_options(options: JsValue) -> Result<(), JsError> {
+    default_segmenter().set_normalize_options(options)
+}
+
+#[wasm_bindgen]
+pub fn get_normalize_options() -> JsValue {
+    default_segmenter().get_normalize_options()
+}
+
+#[wasm_bindgen]
+pub fn normalize(text: &str) -> String {
+    default_segmenter().normalize(text)
+}
+
+#[wasm_bindgen]
+pub fn set_traditional(traditional: bool) {
+    default_segmenter().set_traditional(traditional)
+}
+
+#[wasm_bindgen]
+pub fn is_traditional() -> bool {
+    default_segmenter().is_traditional()
+}
+
+#[wasm_bindgen]
+pub fn to_simplified(text: &str) -> String {
+    convert::to_simplified(text)
+}
+
+#[wasm_bindgen]
+pub fn to_traditional(text: &str) -> String {
+    convert::to_traditional(text)
+}
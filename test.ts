@@ -1,15 +1,54 @@
-import { assertEquals } from "https://deno.land/std@0.148.0/testing/asserts.ts";
 import {
+  assert,
+  assertEquals,
+  assertThrows,
+} from "https://deno.land/std@0.148.0/testing/asserts.ts";
+import {
+  addStopWord,
   addWord,
+  begin,
+  commit,
   cut,
+  cutBatch,
   cutForSearch,
   CutMode,
+  exportDict,
+  exportSnapshot,
+  getStopWords,
+  isTraditional,
+  Jieba,
   loadDict,
+  lookup,
+  lookupBatch,
+  Normalize,
+  OffsetUnit,
+  pinyin,
+  pinyinInitials,
+  PinyinStyle,
+  prefixSearch,
+  removeStopWord,
+  removeWord,
+  removeWords,
   reset,
+  rollback,
+  setTraditional,
+  splitSentences,
   suggestFreq,
   tag,
+  tagNames,
+  TextRank,
+  TFIDF,
+  toSimplified,
   tokenize,
+  tokenizeBatch,
+  tokenizeBinary,
   TokenizeMode,
+  tokenizeSentences,
+  tokenizeWithPinyin,
+  tokenizeWithTags,
+  tokenStream,
+  toTraditional,
+  transaction,
 } from "./mod.ts";
 
 Deno.test("Test reset", () => {
@@ -104,11 +143,52 @@ Deno.test("Test loadDict", () => {
   reset();
 });
 
+Deno.test("Test loadDict with invalid line", () => {
+  assertThrows(
+    () => loadDict(new TextEncoder().encode("中出 10000\n叛徒 abc n")),
+    Error,
+    "line 2 `叛徒 abc n`: frequency `abc` is not a valid integer",
+  );
+  assertEquals(suggestFreq("中出"), 348);
+});
+
 Deno.test("Test suggestFreq", () => {
   assertEquals(suggestFreq("中出"), 348);
   assertEquals(suggestFreq("出了"), 1263);
 });
 
+Deno.test("Test suggestFreq with tune", () => {
+  const jieba = new Jieba();
+  assertEquals(jieba.cut("如果放到post中将出错。"), [
+    "如果",
+    "放到",
+    "post",
+    "中将",
+    "出错",
+    "。",
+  ]);
+  assertEquals(jieba.suggestFreq(["中", "将"]), 494);
+  assertEquals(jieba.lookup("中将").freq, 763);
+  assertEquals(jieba.suggestFreq(["中", "将"], true), 494);
+  assertEquals(jieba.lookup("中将").freq, 494);
+  assertEquals(jieba.cut("如果放到post中将出错。"), [
+    "如果",
+    "放到",
+    "post",
+    "中",
+    "将",
+    "出错",
+    "。",
+  ]);
+  assertEquals(jieba.suggestFreq(["不存", "在的词"], true), 0);
+  assertEquals(jieba.lookup("不存在的词").exists, false);
+
+  assertEquals(jieba.cut("「台中」正确应该不会被切开")[1], "台");
+  assertEquals(jieba.suggestFreq("台中", true), 69);
+  assertEquals(jieba.cut("「台中」正确应该不会被切开")[1], "台中");
+  jieba.free();
+});
+
 Deno.test("Test cut", () => {
   assertEquals(cut("我来到北京清华大学"), [
     "我",
@@ -333,3 +413,767 @@ Deno.test("Test tokenize default with HMM", () => {
     ],
   );
 });
+
+Deno.test("Test Jieba instances are independent", () => {
+  const a = new Jieba();
+  const b = new Jieba();
+
+  a.addWord("中出", 10000, "v");
+  assertEquals(a.suggestFreq("中出"), 10001);
+  assertEquals(b.suggestFreq("中出"), 348);
+  assertEquals(suggestFreq("中出"), 348);
+
+  assertEquals(a.cut("我们中出了一个叛徒"), [
+    "我们",
+    "中出",
+    "了",
+    "一个",
+    "叛徒",
+  ]);
+
+  a.free();
+  b.free();
+});
+
+Deno.test("Test cut keeps spaces and commas inside tokens", () => {
+  assertEquals(cut("Hello, world 1,000"), [
+    "Hello",
+    ",",
+    " ",
+    "world",
+    " ",
+    "1",
+    ",",
+    "000",
+  ]);
+  assertEquals(tokenize("Hello, world"), [
+    { word: "Hello", start: 0, end: 5 },
+    { word: ",", start: 5, end: 6 },
+    { word: " ", start: 6, end: 7 },
+    { word: "world", start: 7, end: 12 },
+  ]);
+});
+
+Deno.test("Test stop words", () => {
+  const sentence =
+    "今天纽约的天气真好啊，京华大酒店的张尧经理吃了一只北京烤鸭。后天纽约的天气不好，昨天纽约的天气也不好，北京烤鸭真好吃";
+  const keywords = () =>
+    TFIDF.extractTags(sentence, 3).map(({ keyword }) => keyword);
+
+  assertEquals(keywords(), ["北京烤鸭", "纽约", "天气"]);
+
+  assertEquals(addStopWord("纽约"), true);
+  assertEquals(getStopWords().includes("纽约"), true);
+  assertEquals(keywords(), ["北京烤鸭", "天气", "不好"]);
+
+  assertEquals(removeStopWord("纽约"), true);
+  assertEquals(keywords(), ["北京烤鸭", "纽约", "天气"]);
+});
+
+Deno.test("Test loadIdf", () => {
+  const jieba = new Jieba();
+  const sentence =
+    "今天纽约的天气真好啊，京华大酒店的张尧经理吃了一只北京烤鸭。后天纽约的天气不好，昨天纽约的天气也不好，北京烤鸭真好吃";
+  const keywords = () =>
+    jieba.TFIDF.extractTags(sentence, 3).map(({ keyword }) => keyword);
+
+  assertThrows(
+    () => jieba.loadIdf(new TextEncoder().encode("纽约 100.0\n天气 x")),
+    Error,
+    "line 2 `天气 x`: idf `x` is not a valid number",
+  );
+  assertEquals(keywords(), ["北京烤鸭", "纽约", "天气"]);
+
+  jieba.loadIdf(new TextEncoder().encode("纽约 100.0"));
+  assertEquals(keywords(), ["纽约", "北京烤鸭", "天气"]);
+
+  jieba.free();
+});
+
+Deno.test("Test TextRank", () => {
+  assertEquals(
+    TextRank.extractTags(
+      "今天纽约的天气真好啊，京华大酒店的张尧经理吃了一只北京烤鸭。后天纽约的天气不好，昨天纽约的天气也不好，北京烤鸭真好吃",
+      4,
+    ).map(({ keyword }) => keyword),
+    ["天气", "纽约", "不好", "北京烤鸭"],
+  );
+});
+
+Deno.test("Test TextRank after stop word and dictionary changes", () => {
+  const jieba = new Jieba();
+  const sentence =
+    "今天纽约的天气真好啊，京华大酒店的张尧经理吃了一只北京烤鸭。后天纽约的天气不好，昨天纽约的天气也不好，北京烤鸭真好吃";
+  const keywords = () =>
+    jieba.TextRank.extractTags(sentence, 4).map(({ keyword }) => keyword);
+
+  assertEquals(keywords(), ["天气", "纽约", "不好", "北京烤鸭"]);
+  jieba.addStopWord("纽约");
+  assertEquals(keywords(), ["天气", "北京烤鸭", "不好", "京华"]);
+  jieba.removeWord("北京烤鸭");
+  assertEquals(keywords(), ["天气", "北京", "烤鸭", "不好"]);
+
+  jieba.free();
+});
+
+Deno.test("Test TextRank options", () => {
+  const jieba = new Jieba();
+  const sentence =
+    "今天纽约的天气真好啊，京华大酒店的张尧经理吃了一只北京烤鸭。后天纽约的天气不好，昨天纽约的天气也不好，北京烤鸭真好吃";
+
+  jieba.TextRank.setOptions({ span: 2, tolerance: 0.0001, max_iterations: 100 });
+  assertEquals(jieba.TextRank.getOptions(), {
+    span: 2,
+    damping_factor: 0.85,
+    tolerance: 0.0001,
+    max_iterations: 100,
+  });
+  assertEquals(
+    jieba.TextRank.extractTags(sentence, 2).map(({ keyword }) => keyword),
+    ["纽约", "天气"],
+  );
+
+  assertThrows(
+    () => jieba.TextRank.setOptions({ damping_factor: 1.5 }),
+    Error,
+    "damping factor 1.5 must be between 0 and 1",
+  );
+
+  jieba.free();
+});
+
+Deno.test("Test tokenize with offset units", () => {
+  const sentence = "😀南京市长江大桥𠀀";
+  assertEquals(
+    tokenize(sentence, TokenizeMode.Default, CutMode.Default, OffsetUnit.Char)
+      .map(({ start, end }) => [start, end]),
+    [[0, 1], [1, 4], [4, 8], [8, 9]],
+  );
+  assertEquals(
+    tokenize(sentence, TokenizeMode.Default, CutMode.Default, OffsetUnit.Utf8)
+      .map(({ start, end }) => [start, end]),
+    [[0, 4], [4, 13], [13, 25], [25, 29]],
+  );
+
+  const tokens = tokenize(
+    sentence,
+    TokenizeMode.Default,
+    CutMode.Default,
+    OffsetUnit.Utf16,
+  );
+  assertEquals(
+    tokens.map(({ start, end }) => sentence.slice(start, end)),
+    tokens.map(({ word }) => word),
+  );
+});
+
+Deno.test("Test tokenizeWithTags", () => {
+  assertEquals(tokenizeWithTags("南京市长江大桥"), [
+    { word: "南京市", tag: "ns", start: 0, end: 3 },
+    { word: "长江大桥", tag: "ns", start: 3, end: 7 },
+  ]);
+});
+
+Deno.test("Test tokenizeWithTags with Search Mode", () => {
+  assertEquals(tokenizeWithTags("南京市长江大桥", TokenizeMode.Search), [
+    { word: "南京", tag: "ns", start: 0, end: 2 },
+    { word: "京市", tag: "ns", start: 1, end: 3 },
+    { word: "南京市", tag: "ns", start: 0, end: 3 },
+    { word: "长江", tag: "ns", start: 3, end: 5 },
+    { word: "大桥", tag: "ns", start: 5, end: 7 },
+    { word: "长江大桥", tag: "ns", start: 3, end: 7 },
+  ]);
+});
+
+Deno.test("Test tokenizeBinary", () => {
+  const sentence = "😀南京市长江大桥";
+  const tokens = tokenizeBinary(
+    sentence,
+    TokenizeMode.Search,
+    CutMode.Default,
+    OffsetUnit.Utf16,
+    true,
+  );
+  const expected = tokenizeWithTags(
+    sentence,
+    TokenizeMode.Search,
+    CutMode.Default,
+    OffsetUnit.Utf16,
+  );
+  assertEquals(tokens.length, expected.length);
+  assertEquals(
+    Array.from(tokens.offsets),
+    expected.flatMap(({ start, end }) => [start, end]),
+  );
+  assertEquals(
+    Array.from(tokens.tags, (id) => tagNames()[id]),
+    expected.map(({ tag }) => tag),
+  );
+  tokens.free();
+
+  const untagged = tokenizeBinary(sentence);
+  assertEquals(Array.from(untagged.offsets), [0, 1, 1, 4, 4, 8]);
+  assertEquals(untagged.tags.length, 0);
+  untagged.free();
+});
+
+Deno.test("Test Jieba.empty and Jieba.withDict", () => {
+  const empty = Jieba.empty();
+  assertEquals(empty.cut("我们中出了一个叛徒"), [
+    "我",
+    "们",
+    "中",
+    "出",
+    "了",
+    "一",
+    "个",
+    "叛",
+    "徒",
+  ]);
+  empty.free();
+
+  const jieba = Jieba.withDict(new TextEncoder().encode("中出 100 v\n叛徒 50 n"));
+  assertEquals(jieba.cut("我们中出了一个叛徒"), [
+    "我",
+    "们",
+    "中出",
+    "了",
+    "一",
+    "个",
+    "叛徒",
+  ]);
+  jieba.free();
+
+  assertThrows(() => Jieba.withDict(new TextEncoder().encode("中出 abc")));
+});
+
+Deno.test("Test reset keeps the starting dictionary", () => {
+  const empty = Jieba.empty();
+  empty.addWord("中出", 100, "v");
+  assertEquals(empty.cut("中出了"), ["中出", "了"]);
+  empty.reset();
+  assertEquals(empty.cut("中出了"), ["中", "出", "了"]);
+  empty.free();
+
+  const jieba = Jieba.withDict(new TextEncoder().encode("中出 100 v"));
+  jieba.removeWord("中出");
+  assertEquals(jieba.cut("中出了"), ["中", "出", "了"]);
+  jieba.reset();
+  assertEquals(jieba.cut("中出了"), ["中出", "了"]);
+  jieba.free();
+});
+
+Deno.test("Test removeWord", () => {
+  assertEquals(removeWord("长江大桥"), true);
+  assertEquals(removeWord("长江大桥"), false);
+  assertEquals(cut("南京市长江大桥"), ["南京市", "长江", "大桥"]);
+  assertEquals(cut("南京市长江大桥", CutMode.All).includes("长江大桥"), false);
+
+  assertEquals(removeWords(["南京市", "不存在的词"]), 1);
+  assertEquals(cut("南京市长江大桥"), ["南京", "市", "长江", "大桥"]);
+
+  reset();
+  assertEquals(cut("南京市长江大桥"), ["南京市", "长江大桥"]);
+});
+
+Deno.test("Test lookup", () => {
+  assertEquals(lookup("长江大桥"), { exists: true, freq: 3858, tag: "ns" });
+  assertEquals(lookup("不存在的词"), { exists: false, freq: 0, tag: "" });
+
+  const jieba = new Jieba();
+  jieba.addWord("不存在的词", 5, "n");
+  assertEquals(jieba.lookupBatch(["不存在的词", "长江大桥"]), [
+    { exists: true, freq: 5, tag: "n" },
+    { exists: true, freq: 3858, tag: "ns" },
+  ]);
+  jieba.free();
+
+  assertEquals(lookupBatch(["不存在的词"]), [
+    { exists: false, freq: 0, tag: "" },
+  ]);
+});
+
+Deno.test("Test prefixSearch", () => {
+  assertEquals(prefixSearch("长江", 3), [
+    { word: "长江", freq: 18930, tag: "ns" },
+    { word: "长江大桥", freq: 3858, tag: "ns" },
+    { word: "长江流域", freq: 1098, tag: "ns" },
+  ]);
+  assertEquals(prefixSearch("长江大桥", 3), [
+    { word: "长江大桥", freq: 3858, tag: "ns" },
+  ]);
+  assertEquals(prefixSearch("不存在的词"), []);
+  assertEquals(prefixSearch("长红大", 2, true), [
+    { word: "长江大桥", freq: 3858, tag: "ns" },
+    { word: "长大", freq: 1498, tag: "ns" },
+  ]);
+  assertEquals(prefixSearch("长江", 1, true), prefixSearch("长江", 1));
+  assertEquals(prefixSearch("江长", 3, true).map(({ word }) => word), [
+    "江长岐",
+    "长",
+    "增长",
+  ]);
+  assertThrows(() => prefixSearch("长江", 0));
+});
+
+Deno.test("Test fuzzy prefixSearch with a deleted character", () => {
+  for (const prefix of ["中华华人", "中华x人民"]) {
+    assertEquals(prefixSearch(prefix, 1, true).map(({ word }) => word), [
+      "中华人民共和国",
+    ]);
+  }
+});
+
+Deno.test("Test fuzzy prefixSearch skips words shorter than the edit", () => {
+  const words = prefixSearch("长江", 100000, true).map(({ word }) => word);
+  assertEquals(words.includes("长"), false);
+  assertEquals(words.includes("江"), true);
+});
+
+Deno.test("Test exportDict", () => {
+  const jieba = new Jieba();
+  assertEquals(jieba.exportDict(true), "");
+
+  jieba.addWord("中出", 10000, "v");
+  jieba.addWord("叛徒们", 5, "n");
+  assertEquals(jieba.exportDict(true), "中出 10000 vn\n叛徒们 5 n\n");
+
+  const copy = Jieba.withDict(new TextEncoder().encode(jieba.exportDict()));
+  assertEquals(copy.exportSnapshot(), jieba.exportSnapshot());
+  copy.free();
+  jieba.free();
+
+  assertEquals(exportDict(true), "");
+});
+
+Deno.test("Test exportDict delta with removed words", () => {
+  const jieba = new Jieba();
+  jieba.removeWord("长江大桥");
+  jieba.addWord("中出", 10000, "v");
+  const delta = jieba.exportDict(true);
+  assertEquals(delta, "长江大桥 -\n中出 10000 vn\n");
+  jieba.free();
+
+  const reloaded = new Jieba();
+  reloaded.loadDict(new TextEncoder().encode(delta));
+  assertEquals(reloaded.lookup("长江大桥").exists, false);
+  assertEquals(reloaded.cut("南京市长江大桥"), ["南京市", "长江", "大桥"]);
+  assertEquals(reloaded.exportDict(true), delta);
+  reloaded.free();
+});
+
+Deno.test("Test exportDict delta keeps the word -", () => {
+  const encoder = new TextEncoder();
+  const jieba = Jieba.withDict(encoder.encode("- 5 x\n长江 3 ns\n"));
+  assertEquals(jieba.lookup("-"), { exists: true, freq: 5, tag: "x" });
+  assertEquals(jieba.lookup("5").exists, false);
+  jieba.removeWord("长江");
+  const delta = jieba.exportDict(true);
+  assertEquals(delta, "长江 -\n");
+
+  const reloaded = Jieba.withDict(encoder.encode(jieba.exportDict()));
+  assertEquals(reloaded.lookup("-"), { exists: true, freq: 5, tag: "x" });
+  reloaded.loadDict(encoder.encode("- -\n"));
+  assertEquals(reloaded.lookup("-").exists, false);
+  reloaded.free();
+  jieba.free();
+});
+
+Deno.test("Test dictionary snapshot", () => {
+  const jieba = new Jieba();
+  jieba.addWord("中出", 10000, "v");
+  const snapshot = jieba.exportSnapshot();
+  jieba.free();
+
+  const restored = Jieba.fromSnapshot(snapshot);
+  assertEquals(restored.cut("我们中出了一个叛徒"), [
+    "我们",
+    "中出",
+    "了",
+    "一个",
+    "叛徒",
+  ]);
+  assertEquals(restored.exportSnapshot(), snapshot);
+
+  reset();
+  restored.loadSnapshot(exportSnapshot());
+  assertEquals(restored.cut("我们中出了一个叛徒"), [
+    "我们",
+    "中",
+    "出",
+    "了",
+    "一个",
+    "叛徒",
+  ]);
+  assertThrows(() => restored.loadSnapshot(snapshot.subarray(0, 100)));
+  assertThrows(() => Jieba.fromSnapshot(new Uint8Array([1, 2, 3])));
+  restored.free();
+});
+
+Deno.test("Test loading a snapshot is faster than a dictionary", () => {
+  const jieba = new Jieba();
+  const dict = new TextEncoder().encode(jieba.exportDict());
+  const snapshot = jieba.exportSnapshot();
+  jieba.free();
+
+  // best of three, to keep a slow run from deciding the comparison
+  const time = (load: () => Jieba) =>
+    Math.min(
+      ...[1, 2, 3].map(() => {
+        const start = performance.now();
+        load().free();
+        return performance.now() - start;
+      }),
+    );
+  const fromSnapshot = time(() => Jieba.fromSnapshot(snapshot));
+  const withDict = time(() => Jieba.withDict(dict));
+  assert(
+    fromSnapshot < withDict,
+    `fromSnapshot took ${fromSnapshot}ms, withDict ${withDict}ms`,
+  );
+});
+
+Deno.test("Test transactions", () => {
+  assertEquals(begin(), 1);
+  addWord("中出", 10000, "v");
+  assertEquals(begin(), 2);
+  removeWord("叛徒");
+  assertEquals(lookup("叛徒").exists, false);
+  rollback();
+  assertEquals(lookup("叛徒").exists, true);
+  assertEquals(lookup("中出").freq, 10000);
+  commit();
+  assertEquals(lookup("中出").freq, 10000);
+  assertThrows(() => commit(), Error, "no open transaction");
+  assertThrows(() => rollback(), Error, "no open transaction");
+  reset();
+
+  assertThrows(() =>
+    transaction(() => {
+      addWord("中出", 10000, "v");
+      loadDict(new TextEncoder().encode("叛徒 abc n"));
+    })
+  );
+  assertEquals(suggestFreq("中出"), 348);
+  assertEquals(transaction(() => addWord("中出", 10000, "v")), 10000);
+  assertEquals(suggestFreq("中出"), 10001);
+  reset();
+
+  const jieba = new Jieba();
+  jieba.begin();
+  jieba.addWord("中出", 10000, "v");
+  assertEquals(lookup("中出").freq, 3);
+  jieba.rollback();
+  assertEquals(jieba.lookup("中出").freq, 3);
+  assertThrows(() => jieba.rollback(), Error, "no open transaction");
+  jieba.free();
+});
+
+Deno.test("Test splitSentences", () => {
+  assertEquals(splitSentences("他说：“你好！”然后走了……真的吗？！是的。"), [
+    { text: "他说：“你好！”", start: 0, end: 8 },
+    { text: "然后走了……", start: 8, end: 14 },
+    { text: "真的吗？！", start: 14, end: 19 },
+    { text: "是的。", start: 19, end: 22 },
+  ]);
+  assertEquals(
+    splitSentences("圆周率是3.14。Hello world. \"Yes.\" She left!\n（附注。）最后"),
+    [
+      { text: "圆周率是3.14。", start: 0, end: 9 },
+      { text: "Hello world.", start: 9, end: 21 },
+      { text: '"Yes."', start: 22, end: 28 },
+      { text: "She left!", start: 29, end: 38 },
+      { text: "（附注。）", start: 39, end: 44 },
+      { text: "最后", start: 44, end: 46 },
+    ],
+  );
+  assertEquals(splitSentences(" \n\n "), []);
+
+  const text = "😀南京市长江大桥。我来到北京";
+  assertEquals(
+    splitSentences(text, OffsetUnit.Utf16).map(({ start, end }) =>
+      text.slice(start, end)
+    ),
+    ["😀南京市长江大桥。", "我来到北京"],
+  );
+});
+
+Deno.test("Test tokenizeSentences", () => {
+  assertEquals(tokenizeSentences("南京市长江大桥。我来到北京"), [
+    {
+      text: "南京市长江大桥。",
+      start: 0,
+      end: 8,
+      tokens: [
+        { word: "南京市", start: 0, end: 3 },
+        { word: "长江大桥", start: 3, end: 7 },
+        { word: "。", start: 7, end: 8 },
+      ],
+    },
+    {
+      text: "我来到北京",
+      start: 8,
+      end: 13,
+      tokens: [
+        { word: "我", start: 8, end: 9 },
+        { word: "来到", start: 9, end: 11 },
+        { word: "北京", start: 11, end: 13 },
+      ],
+    },
+  ]);
+
+  const text = "😀你好。  再见！";
+  const sentences = tokenizeSentences(
+    text,
+    TokenizeMode.Default,
+    CutMode.Default,
+    OffsetUnit.Utf16,
+  );
+  assertEquals(
+    sentences.flatMap(({ tokens }) =>
+      tokens.map(({ start, end }) => text.slice(start, end))
+    ),
+    sentences.flatMap(({ tokens }) => tokens.map(({ word }) => word)),
+  );
+  assertEquals(sentences.map(({ text }) => text), ["😀你好。", "再见！"]);
+});
+
+Deno.test("Test tokenStream", () => {
+  const text =
+    "😀南京市长江大桥，我来到北京清华大学。\r\n他说：“今天天气不错！”Hello world 3.14 ok";
+  for (const offset_unit of [OffsetUnit.Char, OffsetUnit.Utf16]) {
+    const whole = tokenize(
+      text,
+      TokenizeMode.Search,
+      CutMode.HMM,
+      offset_unit,
+    );
+    const stream = tokenStream(TokenizeMode.Search, CutMode.HMM, offset_unit);
+    for (const size of [1, 2, 3, 7]) {
+      const chars = Array.from(text);
+      const tokens = [];
+      for (let i = 0; i < chars.length; i += size) {
+        tokens.push(...stream.push(chars.slice(i, i + size).join("")));
+      }
+      tokens.push(...stream.finish());
+      assertEquals(tokens, whole);
+    }
+
+    const bytes = new TextEncoder().encode(text);
+    const tokens = [];
+    for (let i = 0; i < bytes.length; i += 5) {
+      tokens.push(...stream.push(bytes.subarray(i, i + 5)));
+    }
+    tokens.push(...stream.finish());
+    assertEquals(tokens, whole);
+    stream.free();
+  }
+});
+
+Deno.test("Test tokenStream of a readable stream", async () => {
+  const stream = tokenStream();
+  async function* readable() {
+    yield "我来到北京，清华";
+    yield new TextEncoder().encode("大学。他说");
+  }
+  const tokens = [];
+  for await (const token of stream.tokenize(readable())) {
+    tokens.push(token.word);
+  }
+  assertEquals(tokens, ["我", "来到", "北京", "，", "清华大学", "。", "他", "说"]);
+  stream.free();
+});
+
+Deno.test("Test Normalize", () => {
+  const sentence = "ＡＢＣ公司㈱成立于１９９８年，ＣＥＯ是Ｔｏｍ。";
+  assertEquals(Normalize.getOptions(), {
+    nfkc: false,
+    full_width: false,
+    case_fold: false,
+    zero_width: false,
+  });
+
+  Normalize.setOptions({ nfkc: true, case_fold: true, zero_width: true });
+  assertEquals(
+    Normalize.apply(sentence),
+    "abc公司(株)成立于1998年,ceo是tom。",
+  );
+  assertEquals(cut("ＡＢＣ公司"), cut("abc公司"));
+  assertEquals(cut(sentence).slice(0, 5), ["abc", "公司", "(", "株", ")"]);
+
+  // positions refer to the original text
+  const tokens = tokenize(sentence, TokenizeMode.Default, CutMode.Default, OffsetUnit.Utf16);
+  assertEquals(tokens.slice(0, 5), [
+    { word: "abc", start: 0, end: 3 },
+    { word: "公司", start: 3, end: 5 },
+    { word: "(", start: 5, end: 6 },
+    { word: "株", start: 5, end: 6 },
+    { word: ")", start: 5, end: 6 },
+  ]);
+  assertEquals(
+    tokens.map(({ start, end }) => sentence.slice(start, end)).slice(-4),
+    ["ＣＥＯ", "是", "Ｔｏｍ", "。"],
+  );
+  assertEquals(tokenize("我来\u200b到北京"), [
+    { word: "我", start: 0, end: 1 },
+    { word: "来到", start: 1, end: 4 },
+    { word: "北京", start: 4, end: 6 },
+  ]);
+
+  const stream = tokenStream();
+  const streamed = [...stream.push("ＡＢ"), ...stream.push("Ｃ公司"), ...stream.finish()];
+  assertEquals(streamed, tokenize("ＡＢＣ公司"));
+  stream.free();
+
+  Normalize.setOptions({});
+  assertEquals(cut("ＡＢＣ"), ["Ａ", "Ｂ", "Ｃ"]);
+
+  const jieba = new Jieba();
+  jieba.Normalize.setOptions({ full_width: true });
+  assertEquals(jieba.Normalize.apply("ＡＢＣ　㈱"), "ABC ㈱");
+  assertEquals(Normalize.apply("ＡＢＣ"), "ＡＢＣ");
+  jieba.free();
+});
+
+Deno.test("Test Traditional Chinese", () => {
+  assertEquals(toSimplified("乾隆皇帝的頭髮很乾淨"), "乾隆皇帝的头发很干净");
+  assertEquals(toTraditional("头发的发展"), "頭髮的發展");
+
+  assertEquals(isTraditional(), false);
+  setTraditional(true);
+  assertEquals(isTraditional(), true);
+  assertEquals(cut("我來到北京清華大學"), ["我", "來到", "北京", "清華大學"]);
+  assertEquals(tag("頭髮很乾淨"), [
+    { word: "頭髮", tag: "n" },
+    { word: "很", tag: "zg" },
+    { word: "乾淨", tag: "a" },
+  ]);
+  const tokens = tokenize("頭髮很乾淨");
+  assertEquals(tokens, [
+    { word: "頭髮", start: 0, end: 2 },
+    { word: "很", start: 2, end: 3 },
+    { word: "乾淨", start: 3, end: 5 },
+  ]);
+
+  const stream = tokenStream();
+  const streamed = [...stream.push("頭髮很"), ...stream.push("乾淨"), ...stream.finish()];
+  assertEquals(streamed, tokens);
+  stream.free();
+
+  const jieba = new Jieba();
+  assertEquals(jieba.isTraditional(), false);
+  jieba.free();
+
+  setTraditional(false);
+});
+
+Deno.test("Test keywords of Traditional Chinese", () => {
+  setTraditional(true);
+  const sentence = "今天紐約的天氣真好啊，京華大酒店的張堯經理吃了一隻北京烤鴨。";
+  const tfidf = TFIDF.extractTags(sentence, 4);
+  assertEquals(tfidf.map(({ keyword }) => keyword), [
+    "北京烤鴨",
+    "大酒店",
+    "京華",
+    "紐約",
+  ]);
+  const textrank = TextRank.extractTags(sentence, 4, ["ns", "n"]);
+  assertEquals(textrank.map(({ keyword }) => keyword), [
+    "經理",
+    "天氣",
+    "紐約",
+    "北京烤鴨",
+  ]);
+  setTraditional(false);
+});
+
+Deno.test("Test cutBatch", () => {
+  assertEquals(cutBatch(["南京市长江大桥", "", "我来到北京清华大学"]), [
+    ["南京市", "长江大桥"],
+    [],
+    ["我", "来到", "北京", "清华大学"],
+  ]);
+});
+
+Deno.test("Test tokenizeBatch", () => {
+  const documents = ["南京市长江大桥", "😀我来到北京"];
+  assertEquals(tokenizeBatch(documents), [
+    [
+      { word: "南京市", start: 0, end: 3 },
+      { word: "长江大桥", start: 3, end: 7 },
+    ],
+    [
+      { word: "😀", start: 0, end: 2 },
+      { word: "我", start: 2, end: 3 },
+      { word: "来到", start: 3, end: 5 },
+      { word: "北京", start: 5, end: 7 },
+    ],
+  ]);
+  assertEquals(
+    tokenizeBatch(documents, TokenizeMode.Search)[0],
+    tokenize(documents[0], TokenizeMode.Search),
+  );
+});
+
+Deno.test("Test pinyin", () => {
+  assertEquals(pinyin("去银行，在路上行走"), [
+    { word: "去", pinyin: ["qù"] },
+    { word: "银行", pinyin: ["yín", "háng"] },
+    { word: "，", pinyin: ["，"] },
+    { word: "在", pinyin: ["zài"] },
+    { word: "路上", pinyin: ["lù", "shàng"] },
+    { word: "行走", pinyin: ["xíng", "zǒu"] },
+  ]);
+  const syllables = (style: PinyinStyle) =>
+    pinyin("他长大了", style).flatMap(({ pinyin }) => pinyin);
+  assertEquals(syllables(PinyinStyle.ToneNumber), [
+    "ta1",
+    "zhang3",
+    "da4",
+    "le",
+  ]);
+  assertEquals(syllables(PinyinStyle.Plain), ["ta", "zhang", "da", "le"]);
+  assertEquals(syllables(PinyinStyle.FirstLetter), ["t", "z", "d", "l"]);
+
+  const tokens = tokenizeWithPinyin(
+    "😀重庆",
+    PinyinStyle.Plain,
+    TokenizeMode.Default,
+    CutMode.Default,
+    OffsetUnit.Utf16,
+  );
+  assertEquals(tokens, [
+    { word: "😀", start: 0, end: 2, pinyin: ["😀"] },
+    { word: "重庆", start: 2, end: 4, pinyin: ["chong", "qing"] },
+  ]);
+  assertEquals(pinyinInitials("中国银行2008年"), "zgyh2008n");
+
+  setTraditional(true);
+  assertEquals(pinyin("銀行"), [{ word: "銀行", pinyin: ["yín", "háng"] }]);
+  setTraditional(false);
+});
+
+Deno.test("Test vendored data files match their pinned hashes", async () => {
+  // keep in sync with vendor/README.md
+  const pinned = {
+    "dict.txt":
+      "139519822fe8ab9e10d9d07e68ea0451045380aedaf54ecc51e2a28c6b42a13f",
+    "hmm.model":
+      "f17790586ac86dd048c8adffed052c4bd2b28ed0682972c1275e59040c0589a7",
+    "idf.txt":
+      "501b70ec56c34d90f3f590f1918ca4b1bd617d5b46cd99bd6d17c7de6e4f80ed",
+  };
+  for (const [file, hash] of Object.entries(pinned)) {
+    const data = Deno.readFileSync(
+      new URL(`./vendor/jieba-rs/src/data/${file}`, import.meta.url),
+    );
+    const digest = new Uint8Array(
+      await crypto.subtle.digest("SHA-256", data),
+    );
+    const hex = Array.from(
+      digest,
+      (byte) => byte.toString(16).padStart(2, "0"),
+    );
+    assertEquals(hex.join(""), hash, file);
+  }
+});